@@ -0,0 +1,67 @@
+// Basis points denominator for fees and profit shares
+pub const BASIS_POINTS: u64 = 10000;
+
+pub const SECONDS_PER_DAY: i64 = 86400;
+
+// Upper bound enforced whenever the platform fee is changed
+pub const MAX_PLATFORM_FEE: u64 = 1000; // 10%
+
+// Defaults for a freshly initialized PlatformConfig
+pub const DEFAULT_MIN_CONTRIBUTION: u64 = 1000000; // 0.001 SOL
+pub const DEFAULT_PLATFORM_FEE: u64 = 500; // 5%
+pub const DEFAULT_BACKER_SHARE: u64 = 6000; // 60%
+pub const DEFAULT_ORGANIZER_SHARE: u64 = 3500; // 35%
+pub const DEFAULT_FUNDING_PERIOD: i64 = 30 * SECONDS_PER_DAY;
+pub const DEFAULT_BUDGET_VOTING_PERIOD: i64 = 7 * SECONDS_PER_DAY;
+pub const DEFAULT_GOVERNANCE_CUTOFF: i64 = SECONDS_PER_DAY; // Before event date
+
+// Price feeds: 10^decimals times any u64 kobo amount must fit in a u128
+pub const MAX_PRICE_FEED_DECIMALS: u8 = 18;
+pub const NATIVE_SOL_DECIMALS: u8 = 9;
+
+// Escrow limits, sized into Escrow::SIZE
+pub const MAX_MILESTONES: usize = 10;
+pub const MAX_APPROVERS: usize = 5;
+pub const MAX_MILESTONE_DESCRIPTION_LEN: usize = 64;
+
+// Vendor registry
+pub const MAX_VENDOR_NAME_LEN: usize = 64;
+
+// Receipts
+pub const MAX_RECEIPT_URI_LEN: usize = 200;
+pub const RECEIPT_CHALLENGE_PERIOD: i64 = 3 * SECONDS_PER_DAY;
+pub const RECEIPT_DISPUTE_THRESHOLD: u64 = 100; // 1% of raised power needed to flag a receipt
+
+// Backer votes on milestones with requires_vote, as basis points of voting power
+pub const MILESTONE_VOTE_QUORUM: u64 = 2000; // 20% of raised voting power must vote
+pub const MILESTONE_VOTE_THRESHOLD: u64 = 5000; // More than 50% of votes cast in favour
+
+// One cap per BudgetCategory
+pub const MAX_CATEGORY_CAPS: usize = 8;
+
+// Budget votes
+pub const BUDGET_VOTE_QUORUM: u64 = 3000; // 30% of backers must vote
+pub const MAX_BACKER_VOTE_SHARE: u64 = 1000; // Capped mode: 10% of the target per backer
+
+// No-confidence votes, as basis points of all voting power raised
+pub const NO_CONFIDENCE_PROPOSAL_THRESHOLD: u64 = 1000; // 10% needed to open a vote
+pub const NO_CONFIDENCE_THRESHOLD: u64 = 5000; // More than 50% in favour winds the event down
+
+// Venue seat maps, sized into VenueLayout::SIZE
+pub const MAX_VENUE_ZONES: usize = 10;
+pub const MAX_ZONE_NAME_LEN: usize = 32; // Zone names are used as a PDA seed
+pub const MAX_VENUE_ROWS: u8 = 26; // Rows are labelled A-Z
+
+// Group bookings, sized into GroupOrder::SIZE
+pub const MIN_GROUP_SIZE: usize = 2;
+pub const MAX_GROUP_SIZE: usize = 20;
+
+// Guest tickets a table can issue
+pub const MAX_TABLE_GUESTS: u8 = 20;
+
+// Token Metadata for ticket NFTs
+pub const MAX_NFT_NAME_LEN: usize = 32;
+pub const TICKET_NFT_SYMBOL: &str = "GFTIX";
+
+// Token-2022 ticket mints: the hook resolves the ticket, event and transfer policy
+pub const TICKET_HOOK_EXTRA_ACCOUNTS: usize = 3;
\ No newline at end of file
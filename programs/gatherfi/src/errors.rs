@@ -88,4 +88,244 @@ pub enum GatherFiError {
     
     #[msg("Not a backer")]
     NotBacker,
+    
+    #[msg("Invalid event status transition")]
+    InvalidStatusTransition,
+    
+    #[msg("Event is not funded")]
+    EventNotFunded,
+    
+    #[msg("Funding deadline has passed")]
+    FundingDeadlinePassed,
+    
+    #[msg("Funding is still open")]
+    FundingStillOpen,
+    
+    #[msg("Event has not taken place yet")]
+    EventNotOver,
+    
+    #[msg("Token accounts required for this event's payment mint")]
+    MissingTokenAccounts,
+    
+    #[msg("Token account does not match the event's payment mint")]
+    InvalidPaymentMint,
+    
+    #[msg("Insufficient escrow balance")]
+    InsufficientEscrowBalance,
+    
+    #[msg("Refunds are not available for this event")]
+    RefundNotAvailable,
+    
+    #[msg("Unauthorized")]
+    Unauthorized,
+    
+    #[msg("Price feed is stale")]
+    StalePriceFeed,
+    
+    #[msg("Price feed required for Naira-priced tickets")]
+    PriceFeedRequired,
+    
+    #[msg("Invalid platform configuration")]
+    InvalidConfig,
+    
+    #[msg("Profit split is locked once funding starts")]
+    ProfitSplitLocked,
+    
+    #[msg("Milestone not found")]
+    InvalidMilestone,
+    
+    #[msg("Milestone already released")]
+    MilestoneAlreadyReleased,
+    
+    #[msg("Milestone is not due yet")]
+    MilestoneNotDue,
+    
+    #[msg("Milestone amount does not match")]
+    InvalidMilestoneAmount,
+    
+    #[msg("Too many milestones")]
+    TooManyMilestones,
+    
+    #[msg("Not an escrow approver")]
+    NotApprover,
+    
+    #[msg("Invalid escrow approvers")]
+    InvalidApprovers,
+    
+    #[msg("Not enough milestone approvals")]
+    InsufficientApprovals,
+    
+    #[msg("Milestone does not require a vote")]
+    MilestoneVoteNotRequired,
+    
+    #[msg("Milestone requires a passing backer vote")]
+    MilestoneVoteNotPassed,
+    
+    #[msg("Budget has not been submitted for a vote")]
+    BudgetNotSubmitted,
+    
+    #[msg("A budget version is still being voted on")]
+    BudgetVoteInProgress,
+    
+    #[msg("Budget items must be non-empty and sum to the total")]
+    InvalidBudget,
+    
+    #[msg("Budget revision account required to archive the current version")]
+    MissingBudgetRevision,
+    
+    #[msg("Invalid vendor")]
+    InvalidVendor,
+    
+    #[msg("Vendor is not verified")]
+    VendorNotVerified,
+    
+    #[msg("Invalid budget item")]
+    InvalidBudgetItem,
+    
+    #[msg("Budget item already paid")]
+    BudgetItemAlreadyPaid,
+    
+    #[msg("Invalid delegate")]
+    InvalidDelegate,
+    
+    #[msg("Voter is not a delegate of this backer")]
+    NotDelegate,
+    
+    #[msg("Invalid receipt")]
+    InvalidReceipt,
+    
+    #[msg("Receipt challenge window has closed")]
+    ReceiptChallengeClosed,
+    
+    #[msg("Receipt already flagged")]
+    ReceiptAlreadyFlagged,
+    
+    #[msg("Receipt is not flagged")]
+    ReceiptNotFlagged,
+    
+    #[msg("Escrow releases are frozen by an open dispute")]
+    EscrowDisputed,
+    
+    #[msg("Invalid budget policy")]
+    InvalidBudgetPolicy,
+    
+    #[msg("Budget breaches a category cap")]
+    BudgetCapExceeded,
+    
+    #[msg("Budget caps can't change after the first submission")]
+    BudgetPolicyLocked,
+    
+    #[msg("Budget vote is not decided yet")]
+    BudgetVoteUndecided,
+    
+    #[msg("Invalid ticket tier supply")]
+    InvalidTierSupply,
+    
+    #[msg("Invalid venue layout")]
+    InvalidVenueLayout,
+    
+    #[msg("Venue layout can't change after tickets are sold")]
+    VenueLayoutLocked,
+    
+    #[msg("Venue layout required")]
+    VenueLayoutRequired,
+    
+    #[msg("Zone not found in venue layout")]
+    InvalidZone,
+    
+    #[msg("Zone sold out")]
+    ZoneSoldOut,
+    
+    #[msg("Invalid seat")]
+    InvalidSeat,
+    
+    #[msg("Ticket already has a seat")]
+    SeatAlreadyAssigned,
+    
+    #[msg("Invalid group size")]
+    InvalidGroupSize,
+    
+    #[msg("Every ticket in this group has been issued")]
+    GroupOrderComplete,
+    
+    #[msg("Invalid number of table guests")]
+    InvalidTableGuests,
+    
+    #[msg("Not a table ticket")]
+    NotTableTicket,
+    
+    #[msg("Every guest ticket for this table has been issued")]
+    TableFull,
+    
+    #[msg("Table tickets admit guests through their guest tickets")]
+    TableTicketNotAdmissible,
+    
+    #[msg("Guest tickets are refunded through their table")]
+    TableGuestTicket,
+    
+    #[msg("Every guest ticket must be passed to refund a table")]
+    MissingGuestTickets,
+    
+    #[msg("Token Metadata accounts required for this event")]
+    MissingMetadataAccounts,
+    
+    #[msg("Collection does not belong to this event")]
+    InvalidCollection,
+    
+    #[msg("Ticket collection can only be created once, before sales")]
+    CollectionAlreadyExists,
+    
+    #[msg("Ticket transfers are disabled for this event")]
+    TransfersDisabled,
+    
+    #[msg("Ticket has reached its transfer limit")]
+    TransferLimitReached,
+    
+    #[msg("Ticket transfers are closed for this event")]
+    TransfersClosed,
+    
+    #[msg("Transfer policy required")]
+    TransferPolicyRequired,
+    
+    #[msg("Transfer hook called outside a token transfer")]
+    NotTransferring,
+    
+    #[msg("Ticket transfers move exactly one token")]
+    InvalidTransferAmount,
+    
+    #[msg("Invalid transfer policy")]
+    InvalidTransferPolicy,
+    
+    #[msg("Invalid price feed configuration")]
+    InvalidPriceFeed,
+    
+    #[msg("Milestone vote is still open")]
+    MilestoneVoteInProgress,
+    
+    #[msg("Milestone vote already passed")]
+    MilestoneVotePassed,
+    
+    #[msg("The backer has a delegation for this event that overrides it")]
+    DelegationOverridden,
+    
+    #[msg("No-confidence vote still in progress")]
+    NoConfidenceVoteInProgress,
+    
+    #[msg("Too many open receipt disputes")]
+    TooManyDisputes,
+    
+    #[msg("Receipt amount doesn't match the amount paid out")]
+    ReceiptAmountMismatch,
+    
+    #[msg("Guest tickets must use the table ticket's token program")]
+    TableMintMismatch,
+    
+    #[msg("Arithmetic overflow")]
+    Overflow,
+    
+    #[msg("Payment already has a receipt")]
+    ReceiptAlreadyAttached,
+    
+    #[msg("Every payout needs a receipt before the event can settle")]
+    ReceiptsOutstanding,
 }
\ No newline at end of file
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct AddMilestone<'info> {
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        has_one = organizer @ GatherFiError::NotOrganizer,
+        has_one = escrow,
+        has_one = budget,
+        constraint = event.status == EventStatus::Funded @ GatherFiError::EventNotFunded,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        constraint = budget.approved_version > 0 @ GatherFiError::BudgetNotApproved,
+    )]
+    pub budget: Account<'info, Budget>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", event.key().as_ref()],
+        bump = escrow.bump,
+        constraint = !escrow.is_locked @ GatherFiError::EscrowLocked,
+    )]
+    pub escrow: Account<'info, Escrow>,
+}
+
+pub fn handler(
+    ctx: Context<AddMilestone>,
+    description: String,
+    amount: u64,
+    due_date: i64,
+    requires_vote: bool,
+) -> Result<()> {
+    let budget = &ctx.accounts.budget;
+    let escrow = &mut ctx.accounts.escrow;
+    
+    // Validate milestone
+    require!(amount > 0, GatherFiError::InvalidMilestoneAmount);
+    require!(escrow.milestones.len() < MAX_MILESTONES, GatherFiError::TooManyMilestones);
+    require!(
+        description.len() <= MAX_MILESTONE_DESCRIPTION_LEN,
+        GatherFiError::InvalidMilestone
+    );
+    
+    // All milestones together can't exceed the approved budget version
+    let scheduled: u64 = escrow.milestones.iter().map(|m| m.amount).sum();
+    let scheduled = scheduled.checked_add(amount).unwrap();
+    require!(scheduled <= escrow.milestone_cap, GatherFiError::MilestoneExceedsBudget);
+    
+    let index = escrow.milestones.len() as u8;
+    escrow.milestones.push(Milestone {
+        index,
+        description,
+        amount,
+        due_date,
+        is_released: false,
+        released_at: None,
+        released_by: None,
+        requires_vote,
+        approval_count: 0,
+        has_receipt: false,
+    });
+    escrow.milestone_count = escrow.milestones.len() as u8;
+    
+    msg!("📌 Milestone #{} added: {} lamports due {}", index, amount, due_date);
+    msg!(
+        "📊 Scheduled: {} / {} (budget v{})",
+        scheduled,
+        escrow.milestone_cap,
+        budget.approved_version
+    );
+    if requires_vote {
+        msg!("🗳️  Release requires a backer vote");
+    }
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+#[instruction(milestone_index: u8)]
+pub struct ApproveMilestone<'info> {
+    #[account(mut)]
+    pub approver: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = escrow.approvers.contains(&approver.key()) @ GatherFiError::NotApprover,
+        constraint = !escrow.is_locked @ GatherFiError::EscrowLocked,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    // One approval per (milestone, approver)
+    #[account(
+        init,
+        payer = approver,
+        space = 8 + MilestoneApproval::SIZE,
+        seeds = [b"milestone_approval", escrow.key().as_ref(), &[milestone_index], approver.key().as_ref()],
+        bump
+    )]
+    pub milestone_approval: Account<'info, MilestoneApproval>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl MilestoneApproval {
+    pub const SIZE: usize = 32 + 1 + 32 + 8 + 1;
+}
+
+pub fn handler(ctx: Context<ApproveMilestone>, milestone_index: u8) -> Result<()> {
+    let escrow = &mut ctx.accounts.escrow;
+    let milestone_approval = &mut ctx.accounts.milestone_approval;
+    let clock = Clock::get()?;
+    
+    let milestone = escrow.milestones
+        .get_mut(milestone_index as usize)
+        .ok_or(GatherFiError::InvalidMilestone)?;
+    require!(!milestone.is_released, GatherFiError::MilestoneAlreadyReleased);
+    
+    milestone.approval_count = milestone.approval_count.checked_add(1).unwrap();
+    let approval_count = milestone.approval_count;
+    
+    milestone_approval.escrow = escrow.key();
+    milestone_approval.milestone_index = milestone_index;
+    milestone_approval.approver = ctx.accounts.approver.key();
+    milestone_approval.approved_at = clock.unix_timestamp;
+    milestone_approval.bump = ctx.bumps.milestone_approval;
+    
+    msg!(
+        "✍️  {} approved milestone #{} ({} / {})",
+        ctx.accounts.approver.key(),
+        milestone_index,
+        approval_count,
+        escrow.approvals_needed
+    );
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+#[instruction(position: SeatPosition)]
+pub struct AssignSeat<'info> {
+    // The ticket holder picks a seat, or the organizer assigns one
+    #[account(
+        mut,
+        constraint = authority.key() == ticket.owner
+            || authority.key() == event.organizer @ GatherFiError::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        constraint = event.venue_layout == Some(venue_layout.key()) @ GatherFiError::VenueLayoutRequired,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        seeds = [b"venue_layout", event.key().as_ref()],
+        bump = venue_layout.bump
+    )]
+    pub venue_layout: Account<'info, VenueLayout>,
+    
+    #[account(
+        mut,
+        has_one = event,
+        constraint = !ticket.is_refunded @ GatherFiError::AlreadyRefunded,
+        constraint = ticket.seat.is_none() @ GatherFiError::SeatAlreadyAssigned,
+    )]
+    pub ticket: Account<'info, Ticket>,
+    
+    // Fails to initialize if the seat was already taken
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Seat::SIZE,
+        seeds = [b"seat", event.key().as_ref(), ticket.zone.as_bytes(), &position.seed()],
+        bump
+    )]
+    pub seat: Account<'info, Seat>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl Seat {
+    pub const SIZE: usize = 32 + 4 + MAX_ZONE_NAME_LEN + 3 + 32 + 8 + 1;
+}
+
+pub fn handler(ctx: Context<AssignSeat>, position: SeatPosition) -> Result<()> {
+    let ticket = &mut ctx.accounts.ticket;
+    let seat = &mut ctx.accounts.seat;
+    let clock = Clock::get()?;
+    
+    // Zone capacity was claimed at purchase, only the seat is new
+    let venue_zone = ctx.accounts.venue_layout
+        .zones
+        .iter()
+        .find(|zone| zone.name == ticket.zone)
+        .ok_or(GatherFiError::InvalidZone)?;
+    let label = venue_zone.seat_label(position)?;
+    
+    seat.event = ctx.accounts.event.key();
+    seat.zone = ticket.zone.clone();
+    seat.position = position;
+    seat.ticket = ticket.key();
+    seat.assigned_at = clock.unix_timestamp;
+    seat.bump = ctx.bumps.seat;
+    
+    msg!("💺 Ticket #{} seated at {}", ticket.ticket_number, label);
+    ticket.seat = Some(label);
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+#[instruction(target: ReceiptTarget, receipt_hash: [u8; 32])]
+pub struct AttachReceipt<'info> {
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+    
+    #[account(
+        has_one = escrow,
+        has_one = budget,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(mut)]
+    pub budget: Account<'info, Budget>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", event.key().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    // Required when the paid vendor attaches the receipt instead of the organizer
+    pub vendor: Option<Account<'info, Vendor>>,
+    
+    #[account(
+        init,
+        payer = submitter,
+        space = 8 + Receipt::SIZE,
+        seeds = [b"receipt", event.key().as_ref(), receipt_hash.as_ref()],
+        bump
+    )]
+    pub receipt: Account<'info, Receipt>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl Receipt {
+    pub const SIZE: usize = 32 + 4 + 32 + 32 + 4 + MAX_RECEIPT_URI_LEN + 8 + 8 + 8 + 1 + 33 + 1 + 1 + 1;
+}
+
+pub fn handler(
+    ctx: Context<AttachReceipt>,
+    target: ReceiptTarget,
+    receipt_hash: [u8; 32],
+    uri: String,
+    amount: u64,
+) -> Result<()> {
+    let event = &ctx.accounts.event;
+    let budget = &mut ctx.accounts.budget;
+    let escrow = &mut ctx.accounts.escrow;
+    let submitter = ctx.accounts.submitter.key();
+    let clock = Clock::get()?;
+    
+    require!(
+        amount > 0 && !uri.is_empty() && uri.len() <= MAX_RECEIPT_URI_LEN,
+        GatherFiError::InvalidReceipt
+    );
+    
+    // Receipts only cover money that has actually left escrow, one per payout. Paid
+    // items carry forward into later budget versions, so they are named by the
+    // version they were paid under
+    match target {
+        ReceiptTarget::BudgetItem { version, index } => {
+            let item = budget.items
+                .get_mut(index as usize)
+                .ok_or(GatherFiError::InvalidBudgetItem)?;
+            require!(item.is_paid, GatherFiError::InvalidReceipt);
+            require!(version == item.paid_version, GatherFiError::InvalidBudgetItem);
+            require!(!item.has_receipt, GatherFiError::ReceiptAlreadyAttached);
+            require!(amount == item.amount, GatherFiError::ReceiptAmountMismatch);
+    
+            let is_vendor = ctx.accounts.vendor
+                .as_ref()
+                .is_some_and(|vendor| vendor.key() == item.vendor && vendor.authority == submitter);
+            require!(submitter == event.organizer || is_vendor, GatherFiError::Unauthorized);
+            item.has_receipt = true;
+        }
+        ReceiptTarget::Milestone { index } => {
+            let milestone = escrow.milestones
+                .get_mut(index as usize)
+                .ok_or(GatherFiError::InvalidMilestone)?;
+            require!(milestone.is_released, GatherFiError::InvalidReceipt);
+            require!(!milestone.has_receipt, GatherFiError::ReceiptAlreadyAttached);
+            require!(amount == milestone.amount, GatherFiError::ReceiptAmountMismatch);
+    
+            require!(submitter == event.organizer, GatherFiError::Unauthorized);
+            milestone.has_receipt = true;
+        }
+    }
+    
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.event = event.key();
+    receipt.target = target;
+    receipt.submitted_by = submitter;
+    receipt.receipt_hash = receipt_hash;
+    receipt.uri = uri;
+    receipt.amount = amount;
+    receipt.submitted_at = clock.unix_timestamp;
+    receipt.challenge_ends_at = clock.unix_timestamp + RECEIPT_CHALLENGE_PERIOD;
+    receipt.is_flagged = false;
+    receipt.flagged_by = None;
+    receipt.is_resolved = false;
+    receipt.dispute_upheld = false;
+    receipt.bump = ctx.bumps.receipt;
+    
+    msg!("🧾 Receipt attached for {:?}: {}", target, receipt.uri);
+    msg!("💰 Amount: {}", amount);
+    msg!("⏳ Challenge window ends: {}", receipt.challenge_ends_at);
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct CalculateProfits<'info> {
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = organizer @ GatherFiError::NotOrganizer,
+        has_one = profit_pool,
+        has_one = escrow,
+        has_one = budget,
+        constraint = event.status == EventStatus::Funded @ GatherFiError::EventNotFunded,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        constraint = !profit_pool.is_calculated @ GatherFiError::ProfitsDistributed,
+    )]
+    pub profit_pool: Account<'info, ProfitPool>,
+    
+    pub escrow: Account<'info, Escrow>,
+    
+    pub budget: Account<'info, Budget>,
+    
+    #[account(
+        mut,
+        seeds = [b"organizer", organizer.key().as_ref()],
+        bump = organizer_profile.bump
+    )]
+    pub organizer_profile: Account<'info, OrganizerProfile>,
+}
+
+pub fn handler(ctx: Context<CalculateProfits>) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let profit_pool = &mut ctx.accounts.profit_pool;
+    let escrow = &ctx.accounts.escrow;
+    let organizer_profile = &mut ctx.accounts.organizer_profile;
+    let clock = Clock::get()?;
+    
+    // Profits are only known once the event has taken place
+    require!(clock.unix_timestamp >= event.event_date, GatherFiError::EventNotOver);
+    
+    // Expenses only settle once every payout has a receipt and none is disputed
+    let budget = &ctx.accounts.budget;
+    require!(
+        budget.items.iter().all(|item| !item.is_paid || item.has_receipt)
+            && escrow.milestones.iter().all(|m| !m.is_released || m.has_receipt),
+        GatherFiError::ReceiptsOutstanding
+    );
+    require!(escrow.open_disputes == 0, GatherFiError::EscrowDisputed);
+    
+    // Net profit = revenue - expenses paid from escrow
+    let revenue = profit_pool.total_revenue
+        .checked_add(profit_pool.other_revenue)
+        .unwrap();
+    profit_pool.total_expenses = escrow.released_amount;
+    profit_pool.net_profit = (revenue as i64)
+        .checked_sub(profit_pool.total_expenses as i64)
+        .unwrap();
+    
+    // Split positive profit by basis points
+    let distributable = profit_pool.net_profit.max(0) as u64;
+    let share_of = |bps: u64| -> u64 {
+        (distributable as u128 * bps as u128 / 10000) as u64
+    };
+    profit_pool.backer_amount = share_of(profit_pool.backer_share);
+    profit_pool.organizer_amount = share_of(profit_pool.organizer_share);
+    profit_pool.platform_amount = share_of(profit_pool.platform_share);
+    
+    profit_pool.total_backers = event.total_backers;
+    profit_pool.is_calculated = true;
+    profit_pool.updated_at = clock.unix_timestamp;
+    
+    // Settle the event
+    event.transition_to(EventStatus::Completed)?;
+    event.updated_at = clock.unix_timestamp;
+    
+    // Update organizer track record
+    organizer_profile.record_completion(event.tickets_sold, event.tickets_checked_in)?;
+    organizer_profile.total_raised = organizer_profile.total_raised
+        .checked_add(event.amount_raised)
+        .unwrap();
+    organizer_profile.updated_at = clock.unix_timestamp;
+    
+    msg!("📊 Profits calculated for {}", event.name);
+    msg!("💰 Revenue: {} | Expenses: {}", revenue, profit_pool.total_expenses);
+    msg!("📈 Net profit: {}", profit_pool.net_profit);
+    msg!(
+        "🤝 Backers: {} | Organizer: {} | Platform: {}",
+        profit_pool.backer_amount,
+        profit_pool.organizer_amount,
+        profit_pool.platform_amount
+    );
+    msg!("👥 Organizer attendance: {} bps", organizer_profile.attendance_rate());
+    
+    Ok(())
+}
\ No newline at end of file
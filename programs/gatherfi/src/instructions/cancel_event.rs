@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct CancelEvent<'info> {
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = organizer @ GatherFiError::NotOrganizer,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        seeds = [b"organizer", organizer.key().as_ref()],
+        bump = organizer_profile.bump
+    )]
+    pub organizer_profile: Account<'info, OrganizerProfile>,
+}
+
+pub fn handler(ctx: Context<CancelEvent>) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let organizer_profile = &mut ctx.accounts.organizer_profile;
+    let clock = Clock::get()?;
+    
+    // Only events still raising funds can be cancelled
+    require!(event.status != EventStatus::Cancelled, GatherFiError::AlreadyCancelled);
+    require!(event.status == EventStatus::Funding, GatherFiError::CannotCancelFunded);
+    
+    event.transition_to(EventStatus::Cancelled)?;
+    event.updated_at = clock.unix_timestamp;
+    
+    // Every contribution becomes refundable
+    organizer_profile.events_cancelled = organizer_profile.events_cancelled.checked_add(1).unwrap();
+    organizer_profile.updated_at = clock.unix_timestamp;
+    
+    msg!("🚫 Event cancelled: {}", event.name);
+    msg!("↩️  {} lamports refundable to {} backers", event.amount_raised, event.total_backers);
+    
+    Ok(())
+}
\ No newline at end of file
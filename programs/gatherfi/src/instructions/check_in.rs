@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct CheckIn<'info> {
+    pub staff: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = event.organizer == staff.key() @ GatherFiError::NotOrganizer,
+        constraint = event.status == EventStatus::Funded @ GatherFiError::EventNotFunded,
+        constraint = !event.is_paused @ GatherFiError::EventPaused,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        has_one = event,
+        constraint = !ticket.is_checked_in @ GatherFiError::AlreadyCheckedIn,
+        constraint = !ticket.is_refunded @ GatherFiError::AlreadyRefunded,
+        constraint = !ticket.is_table() @ GatherFiError::TableTicketNotAdmissible,
+    )]
+    pub ticket: Account<'info, Ticket>,
+}
+
+pub fn handler(ctx: Context<CheckIn>) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let ticket = &mut ctx.accounts.ticket;
+    let clock = Clock::get()?;
+    
+    // Mark ticket as used
+    ticket.is_checked_in = true;
+    ticket.checked_in_time = Some(clock.unix_timestamp);
+    ticket.check_in_staff = Some(ctx.accounts.staff.key());
+    
+    // Update attendance
+    event.tickets_checked_in = event.tickets_checked_in.checked_add(1).unwrap();
+    event.updated_at = clock.unix_timestamp;
+    
+    msg!("✅ Ticket #{} checked in for {}", ticket.ticket_number, event.name);
+    msg!("👥 Attendance: {} / {}", event.tickets_checked_in, event.tickets_sold);
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::GatherFiError;
+use crate::payments;
+
+#[derive(Accounts)]
+pub struct ClaimProfits<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    
+    #[account(
+        has_one = escrow,
+        has_one = profit_pool,
+        constraint = event.status == EventStatus::Completed @ GatherFiError::NoProfits,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        seeds = [b"profits", event.key().as_ref()],
+        bump = profit_pool.bump,
+        constraint = profit_pool.is_calculated @ GatherFiError::NoProfits,
+    )]
+    pub profit_pool: Account<'info, ProfitPool>,
+    
+    #[account(
+        mut,
+        seeds = [b"contribution", event.key().as_ref(), claimant.key().as_ref()],
+        bump = contribution.bump,
+        constraint = contribution.amount > 0 @ GatherFiError::NotBacker,
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    // One claim per backer
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + ProfitClaim::SIZE,
+        seeds = [b"profit_claim", profit_pool.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub profit_claim: Account<'info, ProfitClaim>,
+    
+    #[account(
+        seeds = [b"escrow", event.key().as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    // Token accounts, required when the event has a payment mint
+    #[account(mut)]
+    pub claimant_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"revenue_vault", event.key().as_ref()],
+        bump
+    )]
+    pub revenue_vault: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl ProfitClaim {
+    pub const SIZE: usize = 32 + 32 + 32 + 8 + 8 + 1;
+}
+
+pub fn handler(ctx: Context<ClaimProfits>) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    // Backer pool split pro-rata by contribution
+    let amount = (ctx.accounts.profit_pool.backer_amount as u128)
+        .checked_mul(ctx.accounts.contribution.amount as u128)
+        .unwrap()
+        .checked_div(ctx.accounts.event.amount_raised as u128)
+        .unwrap() as u64;
+    require!(amount > 0, GatherFiError::NoProfits);
+    
+    let route = payments::token_route(
+        &ctx.accounts.event,
+        ctx.accounts.claimant_payment_account.as_ref(),
+        ctx.accounts.revenue_vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    
+    payments::disburse(
+        &ctx.accounts.escrow,
+        ctx.accounts.profit_pool.to_account_info(),
+        ctx.accounts.claimant.to_account_info(),
+        route,
+        amount,
+    )?;
+    
+    // Record claim
+    let profit_claim = &mut ctx.accounts.profit_claim;
+    profit_claim.claimant = ctx.accounts.claimant.key();
+    profit_claim.event = ctx.accounts.event.key();
+    profit_claim.profit_pool = ctx.accounts.profit_pool.key();
+    profit_claim.amount = amount;
+    profit_claim.claimed_at = clock.unix_timestamp;
+    profit_claim.bump = ctx.bumps.profit_claim;
+    
+    let contribution = &mut ctx.accounts.contribution;
+    contribution.claimed_profits = amount;
+    
+    // Update profit pool
+    let profit_pool = &mut ctx.accounts.profit_pool;
+    profit_pool.backers_paid = profit_pool.backers_paid.checked_add(1).unwrap();
+    if profit_pool.backers_paid >= profit_pool.total_backers {
+        profit_pool.is_distributed = true;
+        profit_pool.distribution_date = Some(clock.unix_timestamp);
+    }
+    profit_pool.updated_at = clock.unix_timestamp;
+    
+    msg!(
+        "💸 {} claimed {} in profits from {}",
+        ctx.accounts.claimant.key(),
+        amount,
+        ctx.accounts.event.name
+    );
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct ConfigureEscrowApprovers<'info> {
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        has_one = organizer @ GatherFiError::NotOrganizer,
+        has_one = escrow,
+        constraint = event.status == EventStatus::Funding @ GatherFiError::EventNotActive,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", event.key().as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureEscrowApprovers>,
+    approvers: Vec<Pubkey>,
+    approvals_needed: u8,
+) -> Result<()> {
+    let event = &ctx.accounts.event;
+    let escrow = &mut ctx.accounts.escrow;
+    
+    // Backers fund under the approver set they saw
+    require!(event.amount_raised == 0, GatherFiError::InvalidApprovers);
+    require!(
+        !approvers.is_empty() && approvers.len() <= MAX_APPROVERS,
+        GatherFiError::InvalidApprovers
+    );
+    require!(
+        approvals_needed > 0 && approvals_needed as usize <= approvers.len(),
+        GatherFiError::InvalidApprovers
+    );
+    for (i, approver) in approvers.iter().enumerate() {
+        require!(!approvers[..i].contains(approver), GatherFiError::InvalidApprovers);
+    }
+    
+    escrow.approvers = approvers;
+    escrow.approvals_needed = approvals_needed;
+    escrow.requires_approval = true;
+    
+    msg!(
+        "🔐 Escrow for {} requires {} of {} approvers",
+        event.name,
+        escrow.approvals_needed,
+        escrow.approvers.len()
+    );
+    
+    Ok(())
+}
\ No newline at end of file
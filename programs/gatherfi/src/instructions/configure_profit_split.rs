@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct ConfigureProfitSplit<'info> {
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        has_one = organizer @ GatherFiError::NotOrganizer,
+        has_one = profit_pool,
+        constraint = event.status == EventStatus::Funding @ GatherFiError::EventNotActive,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        seeds = [b"profits", event.key().as_ref()],
+        bump = profit_pool.bump
+    )]
+    pub profit_pool: Account<'info, ProfitPool>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureProfitSplit>,
+    backer_share: u64,
+    organizer_share: u64,
+) -> Result<()> {
+    let event = &ctx.accounts.event;
+    let profit_pool = &mut ctx.accounts.profit_pool;
+    let clock = Clock::get()?;
+    
+    // Backers fund under the split they saw, so it locks with the first contribution
+    require!(event.amount_raised == 0, GatherFiError::ProfitSplitLocked);
+    
+    // Platform share stays as snapshotted from the platform config
+    let total_share = backer_share
+        .checked_add(organizer_share)
+        .and_then(|total| total.checked_add(profit_pool.platform_share));
+    require!(
+        total_share == Some(BASIS_POINTS),
+        GatherFiError::InvalidProfitDistribution
+    );
+    
+    profit_pool.backer_share = backer_share;
+    profit_pool.organizer_share = organizer_share;
+    profit_pool.updated_at = clock.unix_timestamp;
+    
+    msg!("💰 Profit split for {} set", event.name);
+    msg!(
+        "🤝 Backers: {} bps | Organizer: {} bps | Platform: {} bps",
+        backer_share,
+        organizer_share,
+        profit_pool.platform_share
+    );
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+#[instruction(ticket_type: TicketType)]
+pub struct ConfigureTicketTier<'info> {
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        has_one = organizer @ GatherFiError::NotOrganizer,
+        constraint = event.status != EventStatus::Cancelled @ GatherFiError::EventNotActive,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        init_if_needed,
+        payer = organizer,
+        space = 8 + TicketTier::SIZE,
+        seeds = [b"ticket_tier", event.key().as_ref(), &ticket_type.seed()],
+        bump
+    )]
+    pub ticket_tier: Account<'info, TicketTier>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl TicketTier {
+    pub const SIZE: usize = 32 + 1 + 8 + 4 + 4 + 1 + 1 + 8 + 8 + 1;
+}
+
+pub fn handler(
+    ctx: Context<ConfigureTicketTier>,
+    ticket_type: TicketType,
+    price: u64,
+    supply: u32,
+    guests_per_ticket: u8,
+    is_active: bool,
+) -> Result<()> {
+    let event = &ctx.accounts.event;
+    let ticket_tier = &mut ctx.accounts.ticket_tier;
+    let clock = Clock::get()?;
+    
+    require!(price > 0, GatherFiError::InvalidTicketPrice);
+    
+    // Tickets already sold stay valid, so supply can't drop below them
+    require!(
+        supply > 0 && supply >= ticket_tier.sold && supply <= event.max_tickets,
+        GatherFiError::InvalidTierSupply
+    );
+    
+    // Only tables issue guest tickets
+    if ticket_type == TicketType::Table {
+        require!(
+            guests_per_ticket > 0 && guests_per_ticket <= MAX_TABLE_GUESTS,
+            GatherFiError::InvalidTableGuests
+        );
+    } else {
+        require!(guests_per_ticket == 0, GatherFiError::InvalidTableGuests);
+    }
+    
+    if ticket_tier.event == Pubkey::default() {
+        ticket_tier.event = event.key();
+        ticket_tier.ticket_type = ticket_type;
+        ticket_tier.sold = 0;
+        ticket_tier.created_at = clock.unix_timestamp;
+        ticket_tier.bump = ctx.bumps.ticket_tier;
+    }
+    
+    ticket_tier.price = price;
+    ticket_tier.supply = supply;
+    ticket_tier.guests_per_ticket = guests_per_ticket;
+    ticket_tier.is_active = is_active;
+    ticket_tier.updated_at = clock.unix_timestamp;
+    
+    msg!("🎫 {:?} tier for {}", ticket_type, event.name);
+    msg!(
+        "💰 Price: {} | Supply: {} | Sold: {} | Active: {}",
+        price,
+        supply,
+        ticket_tier.sold,
+        is_active
+    );
+    
+    Ok(())
+}
\ No newline at end of file
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 use crate::state::*;
 use crate::errors::GatherFiError;
+use crate::payments;
 
 #[derive(Accounts)]
 pub struct Contribute<'info> {
@@ -9,8 +11,7 @@ pub struct Contribute<'info> {
     
     #[account(
         mut,
-        constraint = event.is_active @ GatherFiError::EventNotActive,
-        constraint = !event.is_cancelled @ GatherFiError::AlreadyCancelled,
+        constraint = event.status == EventStatus::Funding @ GatherFiError::EventNotActive,
         constraint = !event.is_paused @ GatherFiError::EventPaused,
     )]
     pub event: Account<'info, Event>,
@@ -27,15 +28,27 @@ pub struct Contribute<'info> {
     #[account(
         mut,
         seeds = [b"escrow", event.key().as_ref()],
-        bump = event.bump
+        bump = escrow.bump
     )]
     pub escrow: Account<'info, Escrow>,
     
+    // Token accounts, required when the event has a payment mint
+    #[account(mut)]
+    pub contributor_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", event.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
     pub system_program: Program<'info, System>,
 }
 
 impl Contribution {
-    pub const SIZE: usize = 32 + 32 + 8 + 8 + 8 + 1 + 8 + 1;
+    pub const SIZE: usize = 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 8 + 1;
 }
 
 pub fn handler(
@@ -49,17 +62,20 @@ pub fn handler(
     
     // Validate contribution
     require!(amount >= event.min_contribution, GatherFiError::InsufficientContribution);
-    require!(clock.unix_timestamp < event.funding_deadline, "Funding deadline passed");
-    require!(!event.is_funded, GatherFiError::TargetReached);
+    require!(clock.unix_timestamp < event.funding_deadline, GatherFiError::FundingDeadlinePassed);
     
     // Check if this is first contribution
     let is_new_contributor = contribution.amount == 0;
     
+    // Freeze voting power for any budget already up for a vote
+    contribution.checkpoint(event.voting_snapshot_at);
+    
     // Update contribution
+    let previous_weight = event.vote_weight(contribution.voting_power);
     contribution.contributor = ctx.accounts.contributor.key();
     contribution.event = event.key();
     contribution.amount = contribution.amount.checked_add(amount).unwrap();
-    contribution.voting_power = contribution.amount; // 1 lamport = 1 vote
+    contribution.voting_power = contribution.amount; // 1 lamport = 1 vote, weighted by voting_mode
     contribution.claimed_profits = 0;
     contribution.claimed_refund = false;
     
@@ -73,33 +89,40 @@ pub fn handler(
     
     // Update event
     event.amount_raised = event.amount_raised.checked_add(amount).unwrap();
+    event.total_voting_weight = event.total_voting_weight
+        .checked_sub(previous_weight)
+        .unwrap()
+        .checked_add(event.vote_weight(contribution.voting_power))
+        .unwrap();
     event.updated_at = clock.unix_timestamp;
     
     // Update escrow
     escrow.total_amount = escrow.total_amount.checked_add(amount).unwrap();
     escrow.balance = escrow.balance.checked_add(amount).unwrap();
     
-    // Check if funding target reached
+    // Funding target reached; finalize_funding moves the event to Funded
     if event.amount_raised >= event.target_amount {
-        event.is_funded = true;
         msg!("🎯 Funding target reached for {}!", event.name);
     }
     
-    // Transfer SOL to escrow
-    let transfer_instruction = anchor_lang::system_program::Transfer {
-        from: ctx.accounts.contributor.to_account_info(),
-        to: ctx.accounts.escrow.to_account_info(),
-    };
-    
-    let cpi_context = CpiContext::new(
-        ctx.accounts.system_program.to_account_info(),
-        transfer_instruction,
-    );
-    
-    anchor_lang::system_program::transfer(cpi_context, amount)?;
+    // Transfer SOL or payment tokens to escrow
+    let route = payments::token_route(
+        event,
+        ctx.accounts.contributor_payment_account.as_ref(),
+        ctx.accounts.escrow_vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    
+    payments::collect(
+        &ctx.accounts.contributor,
+        ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.system_program,
+        route,
+        amount,
+    )?;
     
     msg!(
-        "✅ {} contributed {} lamports to {}",
+        "✅ {} contributed {} to {}",
         ctx.accounts.contributor.key(),
         amount,
         event.name
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Mint, Transfer};
+use anchor_spl::token::Mint;
 use crate::state::*;
+use crate::constants::*;
 use crate::errors::GatherFiError;
 
 #[derive(Accounts)]
@@ -8,11 +9,29 @@ pub struct CreateEvent<'info> {
     #[account(mut)]
     pub organizer: Signer<'info>,
     
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    // Created on the organizer's first event. Events are addressed as
+    // [b"event", organizer, index] for index in 0..events_created, so clients
+    // can enumerate an organizer's events without an indexer
+    #[account(
+        init_if_needed,
+        payer = organizer,
+        space = 8 + OrganizerProfile::SIZE,
+        seeds = [b"organizer", organizer.key().as_ref()],
+        bump
+    )]
+    pub organizer_profile: Account<'info, OrganizerProfile>,
+    
     #[account(
         init,
         payer = organizer,
         space = 8 + Event::SIZE,
-        seeds = [b"event", organizer.key().as_ref()],
+        seeds = [b"event", organizer.key().as_ref(), &organizer_profile.events_created.to_le_bytes()],
         bump
     )]
     pub event: Account<'info, Event>,
@@ -44,15 +63,28 @@ pub struct CreateEvent<'info> {
     )]
     pub budget: Account<'info, Budget>,
     
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + TicketCounter::SIZE,
+        seeds = [b"ticket_counter", event.key().as_ref()],
+        bump
+    )]
+    pub ticket_counter: Account<'info, TicketCounter>,
+    
     #[account(
         init_if_needed,
         payer = organizer,
-        space = 8 + 8,
+        space = 8 + EventCounter::SIZE,
         seeds = [b"event_counter"],
         bump
     )]
     pub event_counter: Account<'info, EventCounter>,
     
+    // Optional SPL payment mint (e.g. USDC); events without one are priced in SOL.
+    // Token vaults are created afterwards by initialize_escrow
+    pub payment_mint: Option<Account<'info, Mint>>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -62,20 +94,40 @@ pub struct EventCounter {
     pub bump: u8,
 }
 
+impl EventCounter {
+    pub const SIZE: usize = 8 + 1;
+}
+
+impl OrganizerProfile {
+    pub const SIZE: usize = 32 + 8 + 4 + 4 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
+}
+
 impl Event {
-    pub const SIZE: usize = 32 + 256 + 256 + 1 + 8 + 8 + 8 + 8 + 8 + 4 + 4 + 8 + 256 + 64 + 64 + 64 + 1 + 1 + 1 + 1 + 1 + 4 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 32 + 32 + 32 + 1;
+    pub const SIZE: usize = 32 + 8 + 256 + 256 + 1 + 8 + 8 + 8 + 33 + 8 + 8 + 1 + 4 + 4 + 4 + 8 + 256 + 64 + 64 + 64 + 1 + 1 + 1 + 4 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 32 + 32 + 32 + 33 + 33 + 33 + 1;
+}
+
+impl Milestone {
+    pub const SIZE: usize = 1 + 4 + MAX_MILESTONE_DESCRIPTION_LEN + 8 + 8 + 1 + 9 + 33 + 1 + 1 + 1;
 }
 
 impl Escrow {
-    pub const SIZE: usize = 32 + 8 + 8 + 8 + 1 + 1 + 1000 + 1 + 1 + 32 + 1 + 8 + 1;
+    pub const SIZE: usize = 32 + 8 + 8 + 8 + 8 + 1 + 1 + 4 + MAX_MILESTONES * Milestone::SIZE + 1 + 1 + 8 + 2 + 1 + 4 + MAX_APPROVERS * 32 + 1 + 8 + 1;
 }
 
 impl ProfitPool {
-    pub const SIZE: usize = 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 8 + 4 + 4 + 8 + 1;
+    pub const SIZE: usize = 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 9 + 4 + 4 + 8 + 8 + 1;
+}
+
+impl TicketCounter {
+    pub const SIZE: usize = 4 + 1;
+}
+
+impl CategoryCap {
+    pub const SIZE: usize = 1 + 8;
 }
 
 impl Budget {
-    pub const SIZE: usize = 32 + 32 + 2000 + 8 + 8 + 8 + 1 + 8 + 8 + 4 + 8 + 1 + 1 + 8 + 8 + 1;
+    pub const SIZE: usize = 32 + 32 + 2000 + 8 + 8 + 8 + 4 + MAX_CATEGORY_CAPS * CategoryCap::SIZE + 2 + 2 + 1 + 8 + 8 + 4 + 8 + 8 + 8 + 8 + 4 + 8 + 1 + 1 + 8 + 8 + 1;
 }
 
 pub fn handler(
@@ -88,12 +140,17 @@ pub fn handler(
     event_date: i64,
     location: String,
     category: EventCategory,
+    priced_in_naira: bool,
+    voting_mode: VotingMode,
 ) -> Result<()> {
     let event = &mut ctx.accounts.event;
     let escrow = &mut ctx.accounts.escrow;
     let profit_pool = &mut ctx.accounts.profit_pool;
     let budget = &mut ctx.accounts.budget;
+    let ticket_counter = &mut ctx.accounts.ticket_counter;
     let event_counter = &mut ctx.accounts.event_counter;
+    let organizer_profile = &mut ctx.accounts.organizer_profile;
+    let platform_config = &ctx.accounts.platform_config;
     let clock = Clock::get()?;
     
     // Validate inputs
@@ -105,16 +162,20 @@ pub fn handler(
     // Validate Nigerian location
     let (city, state) = validate_nigerian_location(&location)?;
     
-    // Initialize event
+    // Initialize event, snapshotting platform parameters
     event.organizer = ctx.accounts.organizer.key();
+    event.index = organizer_profile.events_created;
     event.name = name;
     event.description = description;
     event.category = category;
     event.target_amount = target_amount;
     event.amount_raised = 0;
-    event.min_contribution = 1000000; // 0.001 SOL minimum
+    event.min_contribution = platform_config.min_contribution;
+    event.payment_mint = ctx.accounts.payment_mint.as_ref().map(|mint| mint.key());
     event.ticket_price = ticket_price;
+    event.priced_in_naira = priced_in_naira;
     event.tickets_sold = 0;
+    event.tickets_checked_in = 0;
     event.max_tickets = max_tickets;
     event.revenue_from_tickets = 0;
     event.event_date = event_date;
@@ -123,29 +184,32 @@ pub fn handler(
     event.state = state;
     event.country = "Nigeria".to_string();
     
-    // Status flags
-    event.is_active = true;
-    event.is_funded = false;
-    event.is_cancelled = false;
+    // Status
+    event.status = EventStatus::Funding;
     event.is_paused = false;
-    event.is_finalized = false;
     
     // Governance
+    event.voting_mode = voting_mode;
     event.total_backers = 0;
+    event.total_voting_weight = 0;
     event.total_votes = 0;
     event.votes_for = 0;
     event.votes_against = 0;
-    event.voting_ends_at = event_date - 86400; // 1 day before event
+    event.voting_ends_at = event_date - platform_config.governance_cutoff;
+    event.voting_snapshot_at = 0;
     
     // Timestamps
     event.created_at = clock.unix_timestamp;
     event.updated_at = clock.unix_timestamp;
-    event.funding_deadline = clock.unix_timestamp + 30 * 86400; // 30 days
+    event.funding_deadline = clock.unix_timestamp + platform_config.funding_period;
     
     // PDAs
     event.escrow = ctx.accounts.escrow.key();
     event.profit_pool = ctx.accounts.profit_pool.key();
     event.budget = ctx.accounts.budget.key();
+    event.venue_layout = None;
+    event.collection_mint = None;
+    event.transfer_policy = None;
     event.bump = ctx.bumps.event;
     
     // Initialize escrow
@@ -153,10 +217,14 @@ pub fn handler(
     escrow.total_amount = 0;
     escrow.released_amount = 0;
     escrow.balance = 0;
+    escrow.milestone_cap = 0;
     escrow.milestone_count = 0;
     escrow.current_milestone = 0;
     escrow.milestones = Vec::new();
     escrow.is_locked = false;
+    escrow.is_wound_down = false;
+    escrow.refund_pool = 0;
+    escrow.open_disputes = 0;
     escrow.requires_approval = true;
     escrow.approvers = vec![event.organizer];
     escrow.approvals_needed = 1;
@@ -168,17 +236,21 @@ pub fn handler(
     profit_pool.total_revenue = 0;
     profit_pool.other_revenue = 0;
     profit_pool.total_expenses = 0;
-    profit_pool.platform_fee = 500; // 5% in basis points
+    profit_pool.platform_fee = platform_config.platform_fee;
     profit_pool.net_profit = 0;
-    profit_pool.backer_share = 6000; // 60% in basis points
-    profit_pool.organizer_share = 3500; // 35% in basis points
-    profit_pool.platform_share = 500; // 5% in basis points
+    profit_pool.backer_share = platform_config.backer_share;
+    profit_pool.organizer_share = platform_config.organizer_share;
+    profit_pool.platform_share = platform_config.platform_fee;
+    profit_pool.backer_amount = 0;
+    profit_pool.organizer_amount = 0;
+    profit_pool.platform_amount = 0;
     profit_pool.is_calculated = false;
     profit_pool.is_distributed = false;
     profit_pool.distribution_date = None;
     profit_pool.backers_paid = 0;
     profit_pool.total_backers = 0;
     profit_pool.created_at = clock.unix_timestamp;
+    profit_pool.updated_at = clock.unix_timestamp;
     profit_pool.bump = ctx.bumps.profit_pool;
     
     // Initialize budget
@@ -188,30 +260,60 @@ pub fn handler(
     budget.total_amount = 0;
     budget.amount_spent = 0;
     budget.amount_remaining = 0;
+    budget.category_caps = Vec::new();
+    budget.version = 0;
+    budget.approved_version = 0;
     budget.is_approved = false;
     budget.votes_for = 0;
     budget.votes_against = 0;
     budget.total_voters = 0;
-    budget.voting_ends_at = clock.unix_timestamp + 7 * 86400; // 7 days
+    budget.voting_ends_at = clock.unix_timestamp + platform_config.budget_voting_period;
+    budget.voting_period = platform_config.budget_voting_period;
+    budget.snapshot_at = 0;
+    budget.snapshot_voting_power = 0;
+    budget.snapshot_backers = 0;
+    budget.finalized_at = 0;
     budget.is_locked = false;
     budget.is_completed = false;
     budget.created_at = clock.unix_timestamp;
     budget.updated_at = clock.unix_timestamp;
     budget.bump = ctx.bumps.budget;
     
+    // Initialize ticket counter
+    ticket_counter.count = 0;
+    ticket_counter.bump = ctx.bumps.ticket_counter;
+    
     // Increment event counter
     event_counter.count += 1;
     event_counter.bump = ctx.bumps.event_counter;
     
+    // Update organizer profile
+    if organizer_profile.events_created == 0 {
+        organizer_profile.organizer = event.organizer;
+        organizer_profile.created_at = clock.unix_timestamp;
+        organizer_profile.bump = ctx.bumps.organizer_profile;
+    }
+    organizer_profile.events_created = organizer_profile.events_created.checked_add(1).unwrap();
+    organizer_profile.updated_at = clock.unix_timestamp;
+    
     msg!(
         "🎉 Event created: {} in {}, Nigeria",
         event.name,
         event.city
     );
     msg!("📍 Location: {}", event.location);
-    msg!("💰 Target: {} lamports", event.target_amount);
-    msg!("🎟️  Ticket price: {} lamports", event.ticket_price);
+    msg!("💰 Target: {}", event.target_amount);
+    if event.priced_in_naira {
+        msg!("🎟️  Ticket price: ₦{}.{:02}", event.ticket_price / 100, event.ticket_price % 100);
+    } else {
+        msg!("🎟️  Ticket price: {}", event.ticket_price);
+    }
+    match event.payment_mint {
+        Some(mint) => msg!("🪙 Payment mint: {}", mint),
+        None => msg!("🪙 Payment: native SOL"),
+    }
     msg!("📅 Event date: {}", event.event_date);
+    msg!("🔢 Organizer event #{}", event.index);
     
     Ok(())
 }
\ No newline at end of file
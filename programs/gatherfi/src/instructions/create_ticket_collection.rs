@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::Metadata;
+use anchor_spl::metadata::mpl_token_metadata::types::{CollectionDetails, DataV2};
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+use crate::metadata;
+
+#[derive(Accounts)]
+pub struct CreateTicketCollection<'info> {
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = organizer @ GatherFiError::NotOrganizer,
+        constraint = event.collection_mint.is_none() @ GatherFiError::CollectionAlreadyExists,
+        constraint = event.tickets_sold == 0 @ GatherFiError::CollectionAlreadyExists,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        init,
+        payer = organizer,
+        mint::decimals = 0,
+        mint::authority = event,
+        mint::freeze_authority = event,
+        seeds = [b"collection_mint", event.key().as_ref()],
+        bump
+    )]
+    pub collection_mint: Account<'info, Mint>,
+    
+    // The collection NFT is held by the event PDA
+    #[account(
+        init,
+        payer = organizer,
+        associated_token::mint = collection_mint,
+        associated_token::authority = event,
+    )]
+    pub collection_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Created and validated by the Token Metadata program
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Created and validated by the Token Metadata program
+    #[account(mut)]
+    pub collection_master_edition: UncheckedAccount<'info>,
+    
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<CreateTicketCollection>) -> Result<()> {
+    let clock = Clock::get()?;
+    let event = &ctx.accounts.event;
+    
+    // Mint the single collection token, signed by the event PDA as mint authority
+    let event_seeds = event.signer_seeds();
+    let event_seeds = event_seeds.as_seeds();
+    let signer_seeds = &[&event_seeds[..]];
+    
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.collection_mint.to_account_info(),
+        to: ctx.accounts.collection_token_account.to_account_info(),
+        authority: event.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    
+    token::mint_to(cpi_context, 1)?;
+    
+    // Sized collection, so each verified ticket bumps its size
+    let data = DataV2 {
+        name: metadata::nft_name(&event.name, ""),
+        symbol: TICKET_NFT_SYMBOL.to_string(),
+        uri: format!("https://ipfs.gatherfi.ng/events/{}/collection.json", event.key()),
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+    
+    metadata::create_nft(
+        event,
+        ctx.accounts.organizer.to_account_info(),
+        ctx.accounts.collection_mint.to_account_info(),
+        ctx.accounts.collection_metadata.to_account_info(),
+        ctx.accounts.collection_master_edition.to_account_info(),
+        &ctx.accounts.token_metadata_program,
+        ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program,
+        &ctx.accounts.rent,
+        data,
+        Some(CollectionDetails::V1 { size: 0 }),
+    )?;
+    
+    let event = &mut ctx.accounts.event;
+    event.collection_mint = Some(ctx.accounts.collection_mint.key());
+    event.updated_at = clock.unix_timestamp;
+    
+    msg!("🖼️  Ticket collection created for {}", event.name);
+    msg!("🪙 Collection mint: {}", ctx.accounts.collection_mint.key());
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+#[instruction(event: Option<Pubkey>)]
+pub struct DelegateVotes<'info> {
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+    
+    // One delegation per (backer, event), Pubkey::default() scopes it to all events
+    #[account(
+        init_if_needed,
+        payer = delegator,
+        space = 8 + Delegation::SIZE,
+        seeds = [b"delegation", delegator.key().as_ref(), event.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl Delegation {
+    pub const SIZE: usize = 32 + 32 + 33 + 8 + 1;
+}
+
+pub fn handler(
+    ctx: Context<DelegateVotes>,
+    event: Option<Pubkey>,
+    delegate: Pubkey,
+) -> Result<()> {
+    let delegation = &mut ctx.accounts.delegation;
+    let delegator = ctx.accounts.delegator.key();
+    let clock = Clock::get()?;
+    
+    require!(
+        delegate != delegator && delegate != Pubkey::default(),
+        GatherFiError::InvalidDelegate
+    );
+    
+    delegation.delegator = delegator;
+    delegation.delegate = delegate;
+    delegation.event = event;
+    delegation.created_at = clock.unix_timestamp;
+    delegation.bump = ctx.bumps.delegation;
+    
+    match event {
+        Some(event) => msg!("🤝 {} delegated votes on {} to {}", delegator, event, delegate),
+        None => msg!("🤝 {} delegated votes on all events to {}", delegator, delegate),
+    }
+    
+    Ok(())
+}
\ No newline at end of file
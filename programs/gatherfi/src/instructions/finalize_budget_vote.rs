@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct FinalizeBudgetVote<'info> {
+    // Anyone can settle a decided vote
+    pub caller: Signer<'info>,
+    
+    #[account(
+        has_one = budget,
+        has_one = escrow,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        constraint = budget.version > 0 @ GatherFiError::BudgetNotSubmitted,
+        constraint = !budget.is_locked @ GatherFiError::AlreadyFinalized,
+    )]
+    pub budget: Account<'info, Budget>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", event.key().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+}
+
+pub fn handler(ctx: Context<FinalizeBudgetVote>) -> Result<()> {
+    let budget = &mut ctx.accounts.budget;
+    let escrow = &mut ctx.accounts.escrow;
+    let clock = Clock::get()?;
+    
+    let approved = budget
+        .vote_outcome(clock.unix_timestamp)
+        .ok_or(GatherFiError::BudgetVoteUndecided)?;
+    
+    budget.is_locked = true;
+    budget.is_approved = approved;
+    budget.finalized_at = clock.unix_timestamp;
+    budget.updated_at = clock.unix_timestamp;
+    
+    if approved {
+        // Supersede the previous approved version, milestones are now capped by this one
+        budget.approved_version = budget.version;
+        budget.amount_remaining = budget.total_amount.saturating_sub(budget.amount_spent);
+        escrow.milestone_cap = budget.total_amount;
+    
+        msg!("✅ Budget v{} approved for {}", budget.version, ctx.accounts.event.name);
+    } else {
+        msg!("❌ Budget v{} rejected for {}", budget.version, ctx.accounts.event.name);
+    }
+    msg!(
+        "📊 Final tally: {} for | {} against | {} of {} backers voted",
+        budget.votes_for,
+        budget.votes_against,
+        budget.total_voters,
+        budget.snapshot_backers
+    );
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct FinalizeFunding<'info> {
+    // Permissionless so backers can settle a failed round without the organizer
+    pub caller: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = event.status == EventStatus::Funding @ GatherFiError::AlreadyFinalized,
+        constraint = !event.is_paused @ GatherFiError::EventPaused,
+    )]
+    pub event: Account<'info, Event>,
+}
+
+pub fn handler(ctx: Context<FinalizeFunding>) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let clock = Clock::get()?;
+    
+    if event.amount_raised >= event.target_amount {
+        // Target met: close the round early and open ticket sales
+        event.transition_to(EventStatus::Funded)?;
+        
+        msg!("🎯 {} is funded!", event.name);
+        msg!("💰 Raised: {} / {}", event.amount_raised, event.target_amount);
+    } else {
+        // Below target: only fails once the deadline has passed
+        require!(
+            clock.unix_timestamp >= event.funding_deadline,
+            GatherFiError::FundingStillOpen
+        );
+        event.transition_to(EventStatus::FailedFunding)?;
+        
+        msg!("❌ {} failed to reach its funding target", event.name);
+        msg!("💰 Raised: {} / {}", event.amount_raised, event.target_amount);
+        msg!("↩️  Backers can now claim refunds");
+    }
+    
+    event.updated_at = clock.unix_timestamp;
+    
+    Ok(())
+}
\ No newline at end of file
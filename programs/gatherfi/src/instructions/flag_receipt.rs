@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct FlagReceipt<'info> {
+    pub backer: Signer<'info>,
+    
+    #[account(
+        has_one = escrow,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", event.key().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        seeds = [b"contribution", event.key().as_ref(), backer.key().as_ref()],
+        bump = contribution.bump,
+        constraint = contribution.voting_power > 0 @ GatherFiError::NotBacker,
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    #[account(
+        mut,
+        has_one = event,
+    )]
+    pub receipt: Account<'info, Receipt>,
+}
+
+pub fn handler(ctx: Context<FlagReceipt>) -> Result<()> {
+    let receipt = &mut ctx.accounts.receipt;
+    let escrow = &mut ctx.accounts.escrow;
+    let clock = Clock::get()?;
+    
+    // Freezing escrow takes a real stake, not the smallest possible contribution
+    let voting_power = ctx.accounts.contribution.voting_power;
+    require!(
+        voting_power as u128 * BASIS_POINTS as u128
+            >= ctx.accounts.event.amount_raised as u128 * RECEIPT_DISPUTE_THRESHOLD as u128,
+        GatherFiError::InsufficientVotingPower
+    );
+    
+    require!(
+        clock.unix_timestamp < receipt.challenge_ends_at,
+        GatherFiError::ReceiptChallengeClosed
+    );
+    require!(
+        !receipt.is_flagged && !receipt.is_resolved,
+        GatherFiError::ReceiptAlreadyFlagged
+    );
+    
+    // Freeze escrow releases until the dispute is resolved
+    receipt.is_flagged = true;
+    receipt.flagged_by = Some(ctx.accounts.backer.key());
+    escrow.open_disputes = escrow.open_disputes
+        .checked_add(1)
+        .ok_or(GatherFiError::TooManyDisputes)?;
+    
+    msg!("🚩 {} flagged receipt {:?}", ctx.accounts.backer.key(), receipt.target);
+    msg!("🔒 Escrow releases frozen, {} open dispute(s)", escrow.open_disputes);
+    
+    Ok(())
+}
\ No newline at end of file
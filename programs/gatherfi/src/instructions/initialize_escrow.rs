@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Mint};
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+// Creates the escrow-owned token vaults for events with a payment mint.
+// Must run before the first contribution to an SPL-denominated event
+#[derive(Accounts)]
+pub struct InitializeEscrow<'info> {
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        has_one = organizer @ GatherFiError::NotOrganizer,
+        has_one = escrow,
+        constraint = event.status == EventStatus::Funding @ GatherFiError::EventNotActive,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        seeds = [b"escrow", event.key().as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        constraint = event.payment_mint == Some(payment_mint.key()) @ GatherFiError::InvalidPaymentMint,
+    )]
+    pub payment_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = organizer,
+        token::mint = payment_mint,
+        token::authority = escrow,
+        seeds = [b"escrow_vault", event.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = organizer,
+        token::mint = payment_mint,
+        token::authority = escrow,
+        seeds = [b"revenue_vault", event.key().as_ref()],
+        bump
+    )]
+    pub revenue_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<InitializeEscrow>) -> Result<()> {
+    msg!("🏦 Escrow vaults initialized for {}", ctx.accounts.event.name);
+    msg!("🪙 Payment mint: {}", ctx.accounts.payment_mint.key());
+    msg!("📥 Contributions: {}", ctx.accounts.escrow_vault.key());
+    msg!("🎟️  Ticket revenue: {}", ctx.accounts.revenue_vault.key());
+    
+    Ok(())
+}
\ No newline at end of file
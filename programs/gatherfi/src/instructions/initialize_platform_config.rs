@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+use crate::program::Gatherfi;
+
+#[derive(Accounts)]
+pub struct InitializePlatformConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PlatformConfig::SIZE,
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    // Only the program's upgrade authority can initialize the platform
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Gatherfi>,
+    
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ GatherFiError::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl PlatformConfig {
+    pub const SIZE: usize = 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
+}
+
+pub fn handler(ctx: Context<InitializePlatformConfig>) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+    let clock = Clock::get()?;
+    
+    platform_config.admin = ctx.accounts.admin.key();
+    platform_config.min_contribution = DEFAULT_MIN_CONTRIBUTION;
+    platform_config.funding_period = DEFAULT_FUNDING_PERIOD;
+    platform_config.platform_fee = DEFAULT_PLATFORM_FEE;
+    platform_config.backer_share = DEFAULT_BACKER_SHARE;
+    platform_config.organizer_share = DEFAULT_ORGANIZER_SHARE;
+    platform_config.budget_voting_period = DEFAULT_BUDGET_VOTING_PERIOD;
+    platform_config.governance_cutoff = DEFAULT_GOVERNANCE_CUTOFF;
+    platform_config.updated_at = clock.unix_timestamp;
+    platform_config.bump = ctx.bumps.platform_config;
+    
+    platform_config.validate()?;
+    
+    msg!("⚙️  Platform config initialized, admin: {}", platform_config.admin);
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+use crate::program::Gatherfi;
+
+#[derive(Accounts)]
+#[instruction(quote_mint: Pubkey)]
+pub struct InitializePriceFeed<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    // Required unless the feed is for native SOL, its decimals are taken from the mint
+    #[account(address = quote_mint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PriceFeed::SIZE,
+        seeds = [b"price_feed", quote_mint.as_ref()],
+        bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+    
+    // Only the program's upgrade authority can create price feeds
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Gatherfi>,
+    
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ GatherFiError::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl PriceFeed {
+    pub const SIZE: usize = 32 + 32 + 1 + 8 + 8 + 8 + 1;
+}
+
+pub fn handler(
+    ctx: Context<InitializePriceFeed>,
+    quote_mint: Pubkey,
+    kobo_per_unit: u64,
+    max_staleness: i64,
+) -> Result<()> {
+    let price_feed = &mut ctx.accounts.price_feed;
+    let clock = Clock::get()?;
+    
+    let decimals = if quote_mint == Pubkey::default() {
+        require!(ctx.accounts.mint.is_none(), GatherFiError::InvalidPriceFeed);
+        NATIVE_SOL_DECIMALS
+    } else {
+        ctx.accounts.mint
+            .as_ref()
+            .ok_or(GatherFiError::InvalidPriceFeed)?
+            .decimals
+    };
+    require!(decimals <= MAX_PRICE_FEED_DECIMALS, GatherFiError::InvalidPriceFeed);
+    require!(kobo_per_unit > 0, GatherFiError::InvalidPriceFeed);
+    require!(max_staleness > 0, GatherFiError::InvalidPriceFeed);
+    
+    price_feed.authority = ctx.accounts.admin.key();
+    price_feed.quote_mint = quote_mint;
+    price_feed.decimals = decimals;
+    price_feed.kobo_per_unit = kobo_per_unit;
+    price_feed.max_staleness = max_staleness;
+    price_feed.updated_at = clock.unix_timestamp;
+    price_feed.bump = ctx.bumps.price_feed;
+    
+    msg!("💱 Price feed created for {}", quote_mint);
+    msg!("🇳🇬 Rate: {} kobo per unit", kobo_per_unit);
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::Metadata;
+use crate::state::*;
+use crate::errors::GatherFiError;
+use crate::metadata;
+
+// Mints the next ticket of a paid group order. Anyone can crank it; the
+// caller covers rent for the new accounts
+#[derive(Accounts)]
+pub struct IssueGroupTicket<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        has_one = event,
+        seeds = [b"group_order", event.key().as_ref(), &group_order.first_ticket.to_le_bytes()],
+        bump = group_order.bump,
+        constraint = !group_order.is_complete() @ GatherFiError::GroupOrderComplete,
+    )]
+    pub group_order: Account<'info, GroupOrder>,
+    
+    /// CHECK: Must be the next recipient on the group order
+    #[account(address = group_order.recipients[group_order.issued as usize])]
+    pub recipient: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Ticket::SIZE,
+        seeds = [b"ticket", event.key().as_ref(), &group_order.next_ticket_number().to_le_bytes()],
+        bump
+    )]
+    pub ticket: Account<'info, Ticket>,
+    
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = event,
+        mint::freeze_authority = event,
+        seeds = [b"ticket_mint", event.key().as_ref(), &group_order.next_ticket_number().to_le_bytes()],
+        bump
+    )]
+    pub ticket_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = ticket_mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    
+    // Token Metadata accounts, required once the event has a ticket collection
+    /// CHECK: Created and validated by the Token Metadata program
+    #[account(mut)]
+    pub ticket_metadata: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Created and validated by the Token Metadata program
+    #[account(mut)]
+    pub ticket_master_edition: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        seeds = [b"collection_mint", event.key().as_ref()],
+        bump
+    )]
+    pub collection_mint: Option<Account<'info, Mint>>,
+    
+    /// CHECK: Validated by the Token Metadata program against collection_mint
+    #[account(mut)]
+    pub collection_metadata: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Validated by the Token Metadata program against collection_mint
+    pub collection_master_edition: Option<UncheckedAccount<'info>>,
+    
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<IssueGroupTicket>) -> Result<()> {
+    let event = &ctx.accounts.event;
+    let group_order = &mut ctx.accounts.group_order;
+    let ticket = &mut ctx.accounts.ticket;
+    
+    let ticket_number = group_order.next_ticket_number();
+    group_order.issued = group_order.issued.checked_add(1).unwrap();
+    
+    // Initialize ticket, paid for when the group was booked
+    ticket.mint = ctx.accounts.ticket_mint.key();
+    ticket.event = event.key();
+    ticket.owner = ctx.accounts.recipient.key();
+    ticket.ticket_number = ticket_number;
+    ticket.ticket_type = TicketType::Group;
+    ticket.zone = group_order.zone.clone();
+    ticket.seat = None; // None until assign_seat
+    ticket.parent = None;
+    ticket.guest_slots = 0;
+    ticket.guests_issued = 0;
+    ticket.is_checked_in = false;
+    ticket.is_refunded = false;
+    ticket.is_transferred = false;
+    ticket.transfer_count = 0;
+    ticket.purchase_price = group_order.price_per_ticket;
+    ticket.purchase_time = group_order.created_at;
+    ticket.checked_in_time = None;
+    ticket.check_in_staff = None;
+    ticket.metadata_uri = format!(
+        "https://ipfs.gatherfi.ng/tickets/{}/{}.json",
+        event.key(),
+        ticket_number
+    );
+    ticket.bump = ctx.bumps.ticket;
+    
+    // Mint NFT ticket, signed by the event PDA as mint authority
+    let event_seeds = event.signer_seeds();
+    let event_seeds = event_seeds.as_seeds();
+    let signer_seeds = &[&event_seeds[..]];
+    
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.ticket_mint.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: ctx.accounts.event.to_account_info(),
+    };
+    
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    
+    token::mint_to(cpi_context, 1)?;
+    
+    // Give the NFT its metadata and verify it into the event's collection
+    let metadata_route = metadata::metadata_route(
+        &ctx.accounts.event,
+        ctx.accounts.ticket_metadata.as_ref(),
+        ctx.accounts.ticket_master_edition.as_ref(),
+        ctx.accounts.collection_mint.as_ref(),
+        ctx.accounts.collection_metadata.as_ref(),
+        ctx.accounts.collection_master_edition.as_ref(),
+        ctx.accounts.token_metadata_program.as_ref(),
+    )?;
+    
+    if let Some(route) = metadata_route {
+        metadata::create_ticket_metadata(
+            &ctx.accounts.event,
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.ticket_mint.to_account_info(),
+            route,
+            ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program,
+            &ctx.accounts.rent,
+            metadata::nft_name(&ctx.accounts.event.name, &format!(" #{}", ticket_number)),
+            ctx.accounts.ticket.metadata_uri.clone(),
+        )?;
+    }
+    
+    msg!(
+        "🎟️  Group ticket #{} ({} of {}) minted for {}",
+        ticket_number,
+        group_order.issued,
+        group_order.recipients.len(),
+        event.name
+    );
+    msg!("👤 Owner: {}", ctx.accounts.recipient.key());
+    
+    Ok(())
+}
\ No newline at end of file
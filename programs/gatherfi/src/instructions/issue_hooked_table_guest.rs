@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface;
+use anchor_spl::associated_token::AssociatedToken;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+use crate::tickets::{self, *};
+
+// Guests of a table sold through mint_hooked_ticket. Their mints are Token-2022 with
+// the same transfer hook, so the event's transfer policy covers them too
+#[derive(Accounts)]
+pub struct IssueHookedTableGuest<'info> {
+    pub table_guest: TableGuestAccounts<'info>,
+    
+    /// CHECK: Only its owning token program is read
+    #[account(
+        address = table_guest.table_ticket.mint,
+        constraint = *table_mint.owner == token_2022_program.key() @ GatherFiError::TableMintMismatch,
+    )]
+    pub table_mint: UncheckedAccount<'info>,
+    
+    // Same PDA as SPL guest mints, keyed on the table and the guest index
+    #[account(
+        init,
+        payer = table_guest.owner,
+        mint::decimals = 0,
+        mint::authority = table_guest.event,
+        mint::freeze_authority = table_guest.event,
+        mint::token_program = token_2022_program,
+        extensions::transfer_hook::authority = table_guest.event,
+        extensions::transfer_hook::program_id = crate::ID,
+        seeds = [
+            b"guest_mint",
+            table_guest.table_ticket.key().as_ref(),
+            &[table_guest.table_ticket.guests_issued],
+        ],
+        bump
+    )]
+    pub guest_mint: InterfaceAccount<'info, token_interface::Mint>,
+    
+    #[account(
+        init,
+        payer = table_guest.owner,
+        associated_token::mint = guest_mint,
+        associated_token::authority = table_guest.guest,
+        associated_token::token_program = token_2022_program,
+    )]
+    pub guest_token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+    
+    /// CHECK: Written by the handler in the transfer hook interface's layout
+    #[account(
+        init,
+        payer = table_guest.owner,
+        space = ExtraAccountMetaList::size_of(TICKET_HOOK_EXTRA_ACCOUNTS).unwrap(),
+        seeds = [b"extra-account-metas", guest_mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+    
+    pub token_2022_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<IssueHookedTableGuest>) -> Result<()> {
+    tickets::init_transfer_hook_accounts(
+        &ctx.accounts.extra_account_meta_list,
+        ctx.accounts.table_guest.guest_ticket.key(),
+        &ctx.accounts.table_guest.event,
+    )?;
+    
+    tickets::issue_guest(
+        &mut ctx.accounts.table_guest,
+        TicketNft {
+            mint: ctx.accounts.guest_mint.to_account_info(),
+            holder: ctx.accounts.guest_token_account.to_account_info(),
+            token_program: ctx.accounts.token_2022_program.to_account_info(),
+        },
+        ctx.bumps.table_guest.guest_ticket,
+    )
+}
\ No newline at end of file
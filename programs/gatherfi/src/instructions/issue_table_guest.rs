@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Mint};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::errors::GatherFiError;
+use crate::tickets::{self, *};
+
+// Guests of an SPL table ticket. Tables sold through mint_hooked_ticket issue their
+// guests with issue_hooked_table_guest, so the hook covers every seat at the table
+#[derive(Accounts)]
+pub struct IssueTableGuest<'info> {
+    pub table_guest: TableGuestAccounts<'info>,
+    
+    /// CHECK: Only its owning token program is read
+    #[account(
+        address = table_guest.table_ticket.mint,
+        constraint = *table_mint.owner == token_program.key() @ GatherFiError::TableMintMismatch,
+    )]
+    pub table_mint: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = table_guest.owner,
+        mint::decimals = 0,
+        mint::authority = table_guest.event,
+        mint::freeze_authority = table_guest.event,
+        seeds = [
+            b"guest_mint",
+            table_guest.table_ticket.key().as_ref(),
+            &[table_guest.table_ticket.guests_issued],
+        ],
+        bump
+    )]
+    pub guest_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = table_guest.owner,
+        associated_token::mint = guest_mint,
+        associated_token::authority = table_guest.guest,
+    )]
+    pub guest_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<IssueTableGuest>) -> Result<()> {
+    tickets::issue_guest(
+        &mut ctx.accounts.table_guest,
+        TicketNft {
+            mint: ctx.accounts.guest_mint.to_account_info(),
+            holder: ctx.accounts.guest_token_account.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        ctx.bumps.table_guest.guest_ticket,
+    )
+}
\ No newline at end of file
@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+use crate::payments;
+
+// Pays for and reserves a whole group at once. The tickets themselves are
+// minted one recipient at a time by issue_group_ticket, in the same
+// transaction for small groups or over several for larger ones
+#[derive(Accounts)]
+pub struct MintGroupTickets<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = event.status == EventStatus::Funded @ GatherFiError::EventNotFunded,
+        constraint = !event.is_paused @ GatherFiError::EventPaused,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        seeds = [b"ticket_counter", event.key().as_ref()],
+        bump = ticket_counter.bump
+    )]
+    pub ticket_counter: Account<'info, TicketCounter>,
+    
+    #[account(
+        mut,
+        seeds = [b"ticket_tier", event.key().as_ref(), &TicketType::Group.seed()],
+        bump = ticket_tier.bump,
+        constraint = ticket_tier.is_active @ GatherFiError::TicketTypeUnavailable,
+    )]
+    pub ticket_tier: Account<'info, TicketTier>,
+    
+    // Keyed on the first ticket number of the group
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + GroupOrder::SIZE,
+        seeds = [b"group_order", event.key().as_ref(), &ticket_counter.count.to_le_bytes()],
+        bump
+    )]
+    pub group_order: Account<'info, GroupOrder>,
+    
+    // Required once the organizer has set a venue layout
+    #[account(
+        mut,
+        seeds = [b"venue_layout", event.key().as_ref()],
+        bump = venue_layout.bump
+    )]
+    pub venue_layout: Option<Account<'info, VenueLayout>>,
+    
+    #[account(
+        mut,
+        seeds = [b"profits", event.key().as_ref()],
+        bump = profit_pool.bump
+    )]
+    pub profit_pool: Account<'info, ProfitPool>,
+    
+    // Required when the event is priced in Naira
+    #[account(
+        seeds = [b"price_feed", event.payment_mint.unwrap_or_default().as_ref()],
+        bump = price_feed.bump
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+    
+    // Token accounts, required when the event has a payment mint
+    #[account(mut)]
+    pub buyer_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"revenue_vault", event.key().as_ref()],
+        bump
+    )]
+    pub revenue_vault: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl GroupOrder {
+    pub const SIZE: usize = 32 + 32 + 64 + 4 + 4 + MAX_GROUP_SIZE * 32 + 1 + 8 + 8 + 1;
+}
+
+pub fn handler(
+    ctx: Context<MintGroupTickets>,
+    recipients: Vec<Pubkey>,
+    zone: String,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let ticket_counter = &mut ctx.accounts.ticket_counter;
+    let ticket_tier = &mut ctx.accounts.ticket_tier;
+    let group_order = &mut ctx.accounts.group_order;
+    let clock = Clock::get()?;
+    
+    require!(clock.unix_timestamp < event.event_date, GatherFiError::EventDatePassed);
+    require!(
+        recipients.len() >= MIN_GROUP_SIZE && recipients.len() <= MAX_GROUP_SIZE,
+        GatherFiError::InvalidGroupSize
+    );
+    let group_size = recipients.len() as u32;
+    
+    // All-or-nothing: the whole group must fit in the tier, the event and the zone
+    let tier_sold = ticket_tier.sold.checked_add(group_size).unwrap();
+    require!(tier_sold <= ticket_tier.supply, GatherFiError::TicketsSoldOut);
+    let tickets_sold = event.tickets_sold.checked_add(group_size).unwrap();
+    require!(tickets_sold <= event.max_tickets, GatherFiError::TicketsSoldOut);
+    
+    if event.venue_layout.is_some() {
+        let venue_layout = ctx.accounts.venue_layout
+            .as_mut()
+            .ok_or(GatherFiError::VenueLayoutRequired)?;
+        let venue_zone = venue_layout.zone_mut(&zone)?;
+        let zone_sold = venue_zone.sold.checked_add(group_size).unwrap();
+        require!(zone_sold <= venue_zone.capacity, GatherFiError::ZoneSoldOut);
+        venue_zone.sold = zone_sold;
+    }
+    
+    // Group tier price is per person
+    let price_per_ticket = if event.priced_in_naira {
+        let price_feed = ctx.accounts.price_feed
+            .as_ref()
+            .ok_or(GatherFiError::PriceFeedRequired)?;
+        price_feed.kobo_to_units(ticket_tier.price, clock.unix_timestamp)?
+    } else {
+        ticket_tier.price
+    };
+    require!(price_per_ticket > 0, GatherFiError::InvalidTicketPrice);
+    let total_price = price_per_ticket.checked_mul(group_size as u64).unwrap();
+    
+    // Transfer payment from buyer to the profit pool (SOL) or revenue vault (tokens)
+    let route = payments::token_route(
+        event,
+        ctx.accounts.buyer_payment_account.as_ref(),
+        ctx.accounts.revenue_vault.as_ref(),
+        Some(&ctx.accounts.token_program),
+    )?;
+    
+    payments::collect(
+        &ctx.accounts.buyer,
+        ctx.accounts.profit_pool.to_account_info(),
+        &ctx.accounts.system_program,
+        route,
+        total_price,
+    )?;
+    
+    // Reserve a contiguous block of ticket numbers for the group
+    let first_ticket = ticket_counter.count;
+    ticket_counter.count = ticket_counter.count.checked_add(group_size).unwrap();
+    ticket_tier.sold = tier_sold;
+    
+    group_order.event = event.key();
+    group_order.buyer = ctx.accounts.buyer.key();
+    group_order.zone = zone;
+    group_order.first_ticket = first_ticket;
+    group_order.recipients = recipients;
+    group_order.issued = 0;
+    group_order.price_per_ticket = price_per_ticket;
+    group_order.created_at = clock.unix_timestamp;
+    group_order.bump = ctx.bumps.group_order;
+    
+    // Update event
+    event.tickets_sold = tickets_sold;
+    event.revenue_from_tickets = event.revenue_from_tickets.checked_add(total_price).unwrap();
+    event.updated_at = clock.unix_timestamp;
+    
+    // Update profit pool
+    let profit_pool = &mut ctx.accounts.profit_pool;
+    profit_pool.total_revenue = profit_pool.total_revenue.checked_add(total_price).unwrap();
+    profit_pool.updated_at = clock.unix_timestamp;
+    
+    msg!("👥 Group of {} booked for {}", group_size, event.name);
+    msg!(
+        "🎟️  Tickets #{} to #{}",
+        first_ticket,
+        first_ticket + group_size - 1
+    );
+    msg!("💰 Total: {} ({} per person)", total_price, price_per_ticket);
+    
+    Ok(())
+}
\ No newline at end of file
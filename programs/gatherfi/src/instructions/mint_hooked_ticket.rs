@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface;
+use anchor_spl::associated_token::AssociatedToken;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use crate::state::*;
+use crate::constants::*;
+use crate::tickets::{self, *};
+
+// Sells a ticket as a Token-2022 NFT whose mint routes every transfer through
+// this program's transfer hook, so the event's transfer policy can't be bypassed.
+// Everything else about the sale matches mint_ticket
+#[derive(Accounts)]
+pub struct MintHookedTicket<'info> {
+    pub sale: TicketSaleAccounts<'info>,
+    
+    // Same PDA as SPL ticket mints, keyed on the ticket number
+    #[account(
+        init,
+        payer = sale.buyer,
+        mint::decimals = 0,
+        mint::authority = sale.event,
+        mint::token_program = token_2022_program,
+        extensions::transfer_hook::authority = sale.event,
+        extensions::transfer_hook::program_id = crate::ID,
+        seeds = [b"ticket_mint", sale.event.key().as_ref(), &sale.ticket_counter.count.to_le_bytes()],
+        bump
+    )]
+    pub ticket_mint: InterfaceAccount<'info, token_interface::Mint>,
+    
+    #[account(
+        init,
+        payer = sale.buyer,
+        associated_token::mint = ticket_mint,
+        associated_token::authority = sale.buyer,
+        associated_token::token_program = token_2022_program,
+    )]
+    pub buyer_token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+    
+    /// CHECK: Written by the handler in the transfer hook interface's layout
+    #[account(
+        init,
+        payer = sale.buyer,
+        space = ExtraAccountMetaList::size_of(TICKET_HOOK_EXTRA_ACCOUNTS).unwrap(),
+        seeds = [b"extra-account-metas", ticket_mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+    
+    pub token_2022_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<MintHookedTicket>,
+    ticket_type: TicketType,
+    zone: String,
+    seat_position: Option<SeatPosition>,
+) -> Result<()> {
+    // Hooked tickets only exist under a transfer policy, which the hook enforces
+    tickets::init_transfer_hook_accounts(
+        &ctx.accounts.extra_account_meta_list,
+        ctx.accounts.sale.ticket.key(),
+        &ctx.accounts.sale.event,
+    )?;
+    
+    tickets::sell_ticket(
+        &mut ctx.accounts.sale,
+        TicketNft {
+            mint: ctx.accounts.ticket_mint.to_account_info(),
+            holder: ctx.accounts.buyer_token_account.to_account_info(),
+            token_program: ctx.accounts.token_2022_program.to_account_info(),
+        },
+        ticket_type,
+        zone,
+        seat_position,
+        ctx.bumps.sale.ticket,
+        ctx.bumps.sale.seat,
+    )
+}
\ No newline at end of file
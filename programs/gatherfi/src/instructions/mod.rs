@@ -20,6 +20,39 @@ pub mod emergency_pause;
 pub mod update_event_category;
 pub mod add_milestone;
 pub mod claim_refund;
+pub mod initialize_price_feed;
+pub mod update_price_feed;
+pub mod initialize_platform_config;
+pub mod update_platform_config;
+pub mod configure_profit_split;
+pub mod configure_escrow_approvers;
+pub mod approve_milestone;
+pub mod open_milestone_vote;
+pub mod vote_on_milestone;
+pub mod register_vendor;
+pub mod verify_vendor;
+pub mod pay_budget_item;
+pub mod delegate_votes;
+pub mod revoke_delegation;
+pub mod open_no_confidence;
+pub mod vote_no_confidence;
+pub mod attach_receipt;
+pub mod flag_receipt;
+pub mod resolve_receipt_dispute;
+pub mod set_budget_policy;
+pub mod set_budget_caps;
+pub mod finalize_budget_vote;
+pub mod configure_ticket_tier;
+pub mod set_venue_layout;
+pub mod assign_seat;
+pub mod mint_group_tickets;
+pub mod issue_group_ticket;
+pub mod issue_table_guest;
+pub mod create_ticket_collection;
+pub mod set_transfer_policy;
+pub mod mint_hooked_ticket;
+pub mod issue_hooked_table_guest;
+pub mod transfer_hook;
 
 pub use create_event::*;
 pub use update_event::*;
@@ -42,4 +75,37 @@ pub use verify_ownership::*;
 pub use emergency_pause::*;
 pub use update_event_category::*;
 pub use add_milestone::*;
-pub use claim_refund::*;
\ No newline at end of file
+pub use claim_refund::*;
+pub use initialize_price_feed::*;
+pub use update_price_feed::*;
+pub use initialize_platform_config::*;
+pub use update_platform_config::*;
+pub use configure_profit_split::*;
+pub use configure_escrow_approvers::*;
+pub use approve_milestone::*;
+pub use open_milestone_vote::*;
+pub use vote_on_milestone::*;
+pub use register_vendor::*;
+pub use verify_vendor::*;
+pub use pay_budget_item::*;
+pub use delegate_votes::*;
+pub use revoke_delegation::*;
+pub use open_no_confidence::*;
+pub use vote_no_confidence::*;
+pub use attach_receipt::*;
+pub use flag_receipt::*;
+pub use resolve_receipt_dispute::*;
+pub use set_budget_policy::*;
+pub use set_budget_caps::*;
+pub use finalize_budget_vote::*;
+pub use configure_ticket_tier::*;
+pub use set_venue_layout::*;
+pub use assign_seat::*;
+pub use mint_group_tickets::*;
+pub use issue_group_ticket::*;
+pub use issue_table_guest::*;
+pub use create_ticket_collection::*;
+pub use set_transfer_policy::*;
+pub use mint_hooked_ticket::*;
+pub use issue_hooked_table_guest::*;
+pub use transfer_hook::*;
\ No newline at end of file
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+#[instruction(milestone_index: u8)]
+pub struct OpenMilestoneVote<'info> {
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        has_one = organizer @ GatherFiError::NotOrganizer,
+        has_one = escrow,
+        has_one = budget,
+        constraint = event.status == EventStatus::Funded @ GatherFiError::EventNotFunded,
+    )]
+    pub event: Account<'info, Event>,
+    
+    pub budget: Account<'info, Budget>,
+    
+    #[account(
+        seeds = [b"escrow", event.key().as_ref()],
+        bump = escrow.bump,
+        constraint = !escrow.is_locked @ GatherFiError::EscrowLocked,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    // Reused across rounds, so a failed vote can be reopened
+    #[account(
+        init_if_needed,
+        payer = organizer,
+        space = 8 + MilestoneProposal::SIZE,
+        seeds = [b"milestone_proposal", escrow.key().as_ref(), &[milestone_index]],
+        bump
+    )]
+    pub milestone_proposal: Account<'info, MilestoneProposal>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl MilestoneProposal {
+    pub const SIZE: usize = 32 + 32 + 1 + 1 + 8 + 8 + 4 + 8 + 8 + 8 + 1;
+}
+
+pub fn handler(ctx: Context<OpenMilestoneVote>, milestone_index: u8) -> Result<()> {
+    let event = &ctx.accounts.event;
+    let escrow = &ctx.accounts.escrow;
+    let milestone_proposal = &mut ctx.accounts.milestone_proposal;
+    let clock = Clock::get()?;
+    
+    let milestone = escrow.milestones
+        .get(milestone_index as usize)
+        .ok_or(GatherFiError::InvalidMilestone)?;
+    require!(milestone.requires_vote, GatherFiError::MilestoneVoteNotRequired);
+    require!(!milestone.is_released, GatherFiError::MilestoneAlreadyReleased);
+    
+    // A previous round can only be retried once it has closed without passing
+    let round = if milestone_proposal.created_at > 0 {
+        require!(
+            clock.unix_timestamp >= milestone_proposal.voting_ends_at,
+            GatherFiError::MilestoneVoteInProgress
+        );
+        require!(
+            !milestone_proposal.has_passed(clock.unix_timestamp),
+            GatherFiError::MilestoneVotePassed
+        );
+        milestone_proposal.round.checked_add(1).ok_or(GatherFiError::Overflow)?
+    } else {
+        0
+    };
+    
+    // Quorum is measured against all voting power raised (1 lamport = 1 vote)
+    let quorum = (event.amount_raised as u128 * MILESTONE_VOTE_QUORUM as u128
+        / BASIS_POINTS as u128) as u64;
+    
+    milestone_proposal.event = event.key();
+    milestone_proposal.escrow = escrow.key();
+    milestone_proposal.milestone_index = milestone_index;
+    milestone_proposal.round = round;
+    milestone_proposal.votes_for = 0;
+    milestone_proposal.votes_against = 0;
+    milestone_proposal.total_voters = 0;
+    milestone_proposal.quorum = quorum;
+    milestone_proposal.voting_ends_at = clock.unix_timestamp + ctx.accounts.budget.voting_period;
+    milestone_proposal.created_at = clock.unix_timestamp;
+    milestone_proposal.bump = ctx.bumps.milestone_proposal;
+    
+    msg!(
+        "🗳️  Vote opened on milestone #{} (round {}): {}",
+        milestone_index,
+        round,
+        milestone.description
+    );
+    msg!("💰 Amount: {}", milestone.amount);
+    msg!("📊 Quorum: {} | Ends: {}", quorum, milestone_proposal.voting_ends_at);
+    
+    Ok(())
+}
\ No newline at end of file
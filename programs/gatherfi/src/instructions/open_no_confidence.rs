@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct OpenNoConfidence<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    #[account(
+        has_one = escrow,
+        has_one = budget,
+        constraint = event.status == EventStatus::Funded @ GatherFiError::EventNotFunded,
+    )]
+    pub event: Account<'info, Event>,
+    
+    pub budget: Account<'info, Budget>,
+    
+    #[account(
+        seeds = [b"escrow", event.key().as_ref()],
+        bump = escrow.bump,
+        constraint = !escrow.is_wound_down @ GatherFiError::EscrowLocked,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        seeds = [b"contribution", event.key().as_ref(), proposer.key().as_ref()],
+        bump = contribution.bump,
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    // Reused across rounds, so a failed vote can be reopened
+    #[account(
+        init_if_needed,
+        payer = proposer,
+        space = 8 + NoConfidenceProposal::SIZE,
+        seeds = [b"no_confidence", event.key().as_ref()],
+        bump
+    )]
+    pub no_confidence_proposal: Account<'info, NoConfidenceProposal>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl NoConfidenceProposal {
+    pub const SIZE: usize = 32 + 32 + 1 + 8 + 8 + 4 + 8 + 8 + 1 + 8 + 1;
+}
+
+pub fn handler(ctx: Context<OpenNoConfidence>) -> Result<()> {
+    let event = &ctx.accounts.event;
+    let proposal = &mut ctx.accounts.no_confidence_proposal;
+    let clock = Clock::get()?;
+    
+    // Only backers with a meaningful stake can put the organizer on notice
+    let voting_power = ctx.accounts.contribution.voting_power;
+    require!(
+        voting_power as u128 * BASIS_POINTS as u128
+            >= event.amount_raised as u128 * NO_CONFIDENCE_PROPOSAL_THRESHOLD as u128,
+        GatherFiError::InsufficientVotingPower
+    );
+    
+    // A previous round can only be retried once it has closed. One that passed has
+    // already wound the event down
+    let round = if proposal.created_at > 0 {
+        require!(
+            clock.unix_timestamp >= proposal.voting_ends_at,
+            GatherFiError::NoConfidenceVoteInProgress
+        );
+        proposal.round.checked_add(1).ok_or(GatherFiError::Overflow)?
+    } else {
+        0
+    };
+    
+    // Passing needs a majority of all voting power raised, not just of votes cast
+    let threshold = (event.amount_raised as u128 * NO_CONFIDENCE_THRESHOLD as u128
+        / BASIS_POINTS as u128) as u64;
+    
+    proposal.event = event.key();
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.round = round;
+    proposal.votes_for = 0;
+    proposal.votes_against = 0;
+    proposal.total_voters = 0;
+    proposal.threshold = threshold;
+    proposal.voting_ends_at = clock.unix_timestamp + ctx.accounts.budget.voting_period;
+    proposal.passed = false;
+    proposal.created_at = clock.unix_timestamp;
+    proposal.bump = ctx.bumps.no_confidence_proposal;
+    
+    msg!(
+        "⚠️  No-confidence vote opened on {} by {} (round {})",
+        event.name,
+        proposal.proposer,
+        round
+    );
+    msg!("📊 Votes needed: {} | Ends: {}", threshold, proposal.voting_ends_at);
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::GatherFiError;
+use crate::payments;
+
+#[derive(Accounts)]
+pub struct PayBudgetItem<'info> {
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        has_one = organizer @ GatherFiError::NotOrganizer,
+        has_one = escrow,
+        has_one = budget,
+        constraint = event.status == EventStatus::Funded @ GatherFiError::EventNotFunded,
+        constraint = !event.is_paused @ GatherFiError::EventPaused,
+    )]
+    pub event: Account<'info, Event>,
+    
+    // Items are paid from the latest version, so it must be the approved one
+    #[account(
+        mut,
+        constraint = budget.is_approved @ GatherFiError::BudgetNotApproved,
+    )]
+    pub budget: Account<'info, Budget>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", event.key().as_ref()],
+        bump = escrow.bump,
+        constraint = !escrow.is_locked @ GatherFiError::EscrowLocked,
+        constraint = escrow.open_disputes == 0 @ GatherFiError::EscrowDisputed,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        constraint = vendor.status == VendorStatus::Verified @ GatherFiError::VendorNotVerified,
+    )]
+    pub vendor: Account<'info, Vendor>,
+    
+    #[account(
+        mut,
+        address = vendor.payout_wallet @ GatherFiError::InvalidVendor,
+    )]
+    pub vendor_wallet: SystemAccount<'info>,
+    
+    // Token accounts, required when the event has a payment mint
+    #[account(
+        mut,
+        constraint = vendor_payment_account.owner == vendor.payout_wallet @ GatherFiError::InvalidVendor,
+    )]
+    pub vendor_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", event.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handler(ctx: Context<PayBudgetItem>, item_index: u8) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    // Validate item
+    let budget = &ctx.accounts.budget;
+    let item = budget.items
+        .get(item_index as usize)
+        .ok_or(GatherFiError::InvalidBudgetItem)?;
+    require!(!item.is_paid, GatherFiError::BudgetItemAlreadyPaid);
+    require_keys_eq!(item.vendor, ctx.accounts.vendor.key(), GatherFiError::InvalidVendor);
+    let amount = item.amount;
+    
+    // Vendor payouts and milestone releases share the approved budget
+    let released = ctx.accounts.escrow.released_amount.checked_add(amount).unwrap();
+    require!(
+        released <= ctx.accounts.escrow.milestone_cap,
+        GatherFiError::MilestoneExceedsBudget
+    );
+    
+    // Pay the vendor from escrow
+    let route = payments::token_route(
+        &ctx.accounts.event,
+        ctx.accounts.vendor_payment_account.as_ref(),
+        ctx.accounts.escrow_vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    
+    payments::disburse(
+        &ctx.accounts.escrow,
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.vendor_wallet.to_account_info(),
+        route,
+        amount,
+    )?;
+    
+    // Update budget
+    let budget = &mut ctx.accounts.budget;
+    let version = budget.version;
+    let item = &mut budget.items[item_index as usize];
+    item.is_paid = true;
+    item.paid_at = Some(clock.unix_timestamp);
+    item.paid_version = version;
+    
+    budget.amount_spent = budget.amount_spent.checked_add(amount).unwrap();
+    budget.amount_remaining = budget.amount_remaining.saturating_sub(amount);
+    budget.updated_at = clock.unix_timestamp;
+    
+    // Update escrow
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.released_amount = released;
+    escrow.balance = escrow.balance.checked_sub(amount).unwrap();
+    
+    msg!("🧾 Paid budget item #{} to {}", item_index, ctx.accounts.vendor.name);
+    msg!("💸 Amount: {}", amount);
+    msg!("📊 Spent: {} | Remaining: {}", budget.amount_spent, budget.amount_remaining);
+    
+    Ok(())
+}
\ No newline at end of file
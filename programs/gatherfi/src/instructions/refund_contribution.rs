@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::GatherFiError;
+use crate::payments;
+
+#[derive(Accounts)]
+pub struct RefundContribution<'info> {
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    
+    #[account(
+        has_one = escrow,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        seeds = [b"contribution", event.key().as_ref(), contributor.key().as_ref()],
+        bump = contribution.bump,
+        constraint = contribution.amount > 0 @ GatherFiError::NotBacker,
+        constraint = !contribution.claimed_refund @ GatherFiError::AlreadyRefunded,
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", event.key().as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"organizer", event.organizer.as_ref()],
+        bump = organizer_profile.bump
+    )]
+    pub organizer_profile: Account<'info, OrganizerProfile>,
+    
+    // Token accounts, required when the event has a payment mint
+    #[account(mut)]
+    pub contributor_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", event.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handler(ctx: Context<RefundContribution>) -> Result<()> {
+    let event = &ctx.accounts.event;
+    let escrow = &ctx.accounts.escrow;
+    let contribution = &ctx.accounts.contribution;
+    
+    // Failed or cancelled events return the full contribution. Events wound down by a
+    // no-confidence vote split whatever escrow had left pro-rata
+    let amount = if matches!(event.status, EventStatus::FailedFunding | EventStatus::Cancelled) {
+        contribution.amount
+    } else if escrow.is_wound_down {
+        (escrow.refund_pool as u128 * contribution.amount as u128
+            / event.amount_raised as u128) as u64
+    } else {
+        return Err(error!(GatherFiError::RefundNotAvailable));
+    };
+    
+    let route = payments::token_route(
+        &ctx.accounts.event,
+        ctx.accounts.contributor_payment_account.as_ref(),
+        ctx.accounts.escrow_vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    
+    payments::disburse(
+        &ctx.accounts.escrow,
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.contributor.to_account_info(),
+        route,
+        amount,
+    )?;
+    
+    // Update contribution
+    let contribution = &mut ctx.accounts.contribution;
+    contribution.claimed_refund = true;
+    contribution.voting_power = 0;
+    
+    // Update escrow
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.balance = escrow.balance.checked_sub(amount).unwrap();
+    
+    // Count refunds against the organizer as they are paid
+    let organizer_profile = &mut ctx.accounts.organizer_profile;
+    organizer_profile.total_refunded = organizer_profile.total_refunded
+        .checked_add(amount)
+        .unwrap();
+    organizer_profile.updated_at = Clock::get()?.unix_timestamp;
+    
+    msg!(
+        "↩️  Refunded {} to {} from {}",
+        amount,
+        ctx.accounts.contributor.key(),
+        ctx.accounts.event.name
+    );
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{self, Burn, FreezeAccount, Mint, TokenInterface};
+use crate::state::*;
+use crate::errors::GatherFiError;
+use crate::payments;
+
+#[derive(Accounts)]
+pub struct RefundTicket<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = escrow,
+        has_one = profit_pool,
+        constraint = matches!(
+            event.status,
+            EventStatus::Funded | EventStatus::WoundDown
+        ) @ GatherFiError::EventNotFunded,
+        constraint = !event.is_paused @ GatherFiError::EventPaused,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        has_one = event,
+        has_one = owner @ GatherFiError::NotTicketOwner,
+        constraint = !ticket.is_refunded @ GatherFiError::AlreadyRefunded,
+        constraint = !ticket.is_checked_in @ GatherFiError::AlreadyCheckedIn,
+        constraint = ticket.parent.is_none() @ GatherFiError::TableGuestTicket,
+    )]
+    pub ticket: Account<'info, Ticket>,
+    
+    #[account(
+        mut,
+        seeds = [b"ticket_tier", event.key().as_ref(), &ticket.ticket_type.seed()],
+        bump = ticket_tier.bump
+    )]
+    pub ticket_tier: Account<'info, TicketTier>,
+    
+    // Required once the organizer has set a venue layout
+    #[account(
+        mut,
+        seeds = [b"venue_layout", event.key().as_ref()],
+        bump = venue_layout.bump
+    )]
+    pub venue_layout: Option<Account<'info, VenueLayout>>,
+    
+    // Required when the ticket holds a seat, which is freed for the next buyer
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"seat", event.key().as_ref(), ticket.zone.as_bytes(), &seat.position.seed()],
+        bump = seat.bump,
+        constraint = seat.ticket == ticket.key() @ GatherFiError::InvalidSeat,
+    )]
+    pub seat: Option<Account<'info, Seat>>,
+    
+    #[account(
+        mut,
+        address = ticket.mint
+    )]
+    pub ticket_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = ticket_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = ticket_token_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"profits", event.key().as_ref()],
+        bump = profit_pool.bump
+    )]
+    pub profit_pool: Account<'info, ProfitPool>,
+    
+    #[account(
+        seeds = [b"escrow", event.key().as_ref()],
+        bump = escrow.bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    // Token accounts, required when the event has a payment mint
+    #[account(mut)]
+    pub owner_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"revenue_vault", event.key().as_ref()],
+        bump
+    )]
+    pub revenue_vault: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    // SPL Token or Token-2022, whichever owns the ticket mint
+    pub ticket_token_program: Interface<'info, TokenInterface>,
+}
+
+// A table's guests are passed as remaining accounts, in issue order: each guest
+// ticket, its mint and the token account holding it, then its seat if the guest
+// picked one of their own
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, RefundTicket<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let refund_amount = ctx.accounts.ticket.purchase_price;
+    
+    // Refunds close when the event starts, unless backers wound it down and it
+    // will never take place
+    if ctx.accounts.event.status == EventStatus::Funded {
+        require!(clock.unix_timestamp < ctx.accounts.event.event_date, GatherFiError::EventDatePassed);
+    }
+    
+    // Refunding a table voids every guest ticket it issued
+    let table = ctx.accounts.ticket.key();
+    let table_seat = ctx.accounts.ticket.seat.clone();
+    let event_seeds = ctx.accounts.event.signer_seeds();
+    let event_seeds = event_seeds.as_seeds();
+    let signer_seeds = &[&event_seeds[..]];
+    let mut guest_accounts = ctx.remaining_accounts.iter();
+    for index in 0..ctx.accounts.ticket.guests_issued {
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"guest_ticket", table.as_ref(), &[index]],
+            &crate::ID,
+        );
+        let account_info = guest_accounts.next().ok_or(GatherFiError::MissingGuestTickets)?;
+        require_keys_eq!(account_info.key(), expected, GatherFiError::MissingGuestTickets);
+    
+        let mut guest_ticket = Account::<Ticket>::try_from(account_info)?;
+        require!(!guest_ticket.is_checked_in, GatherFiError::AlreadyCheckedIn);
+    
+        // The guest can't sign for a burn, so the event freezes the guest NFT instead
+        let guest_mint = guest_accounts.next().ok_or(GatherFiError::MissingGuestTickets)?;
+        require_keys_eq!(guest_mint.key(), guest_ticket.mint, GatherFiError::MissingGuestTickets);
+        let holder_info = guest_accounts.next().ok_or(GatherFiError::MissingGuestTickets)?;
+        let holder = InterfaceAccount::<token_interface::TokenAccount>::try_from(holder_info)?;
+        require!(
+            holder.mint == guest_ticket.mint && holder.amount == 1,
+            GatherFiError::MissingGuestTickets
+        );
+    
+        let cpi_accounts = FreezeAccount {
+            account: holder_info.clone(),
+            mint: guest_mint.clone(),
+            authority: ctx.accounts.event.to_account_info(),
+        };
+    
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.ticket_token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+    
+        token_interface::freeze_account(cpi_context)?;
+    
+        // A guest who picked their own seat, rather than sharing the table's, gives
+        // it up with the table
+        if guest_ticket.seat.is_some() && guest_ticket.seat != table_seat {
+            let seat_info = guest_accounts.next().ok_or(GatherFiError::InvalidSeat)?;
+            let seat = Account::<Seat>::try_from(seat_info)?;
+            require_keys_eq!(seat.ticket, guest_ticket.key(), GatherFiError::InvalidSeat);
+            seat.close(ctx.accounts.owner.to_account_info())?;
+            guest_ticket.seat = None;
+        }
+    
+        guest_ticket.is_refunded = true;
+        guest_ticket.exit(&crate::ID)?;
+    }
+    require!(guest_accounts.next().is_none(), GatherFiError::MissingGuestTickets);
+    
+    // Burn the NFT ticket
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.ticket_mint.to_account_info(),
+        from: ctx.accounts.owner_token_account.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    
+    let cpi_context = CpiContext::new(
+        ctx.accounts.ticket_token_program.to_account_info(),
+        cpi_accounts,
+    );
+    
+    token_interface::burn(cpi_context, 1)?;
+    
+    // Return the purchase price from ticket revenue
+    let route = payments::token_route(
+        &ctx.accounts.event,
+        ctx.accounts.owner_payment_account.as_ref(),
+        ctx.accounts.revenue_vault.as_ref(),
+        Some(&ctx.accounts.token_program),
+    )?;
+    
+    payments::disburse(
+        &ctx.accounts.escrow,
+        ctx.accounts.profit_pool.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        route,
+        refund_amount,
+    )?;
+    
+    // Update ticket
+    let ticket = &mut ctx.accounts.ticket;
+    ticket.is_refunded = true;
+    
+    // Put the ticket back on sale in its tier
+    let ticket_tier = &mut ctx.accounts.ticket_tier;
+    ticket_tier.sold = ticket_tier.sold.checked_sub(1).unwrap();
+    
+    // And in its zone, with the places held for a table's guests, releasing the seat
+    // if it had one
+    if ctx.accounts.event.venue_layout.is_some() {
+        let venue_layout = ctx.accounts.venue_layout
+            .as_mut()
+            .ok_or(GatherFiError::VenueLayoutRequired)?;
+        let venue_zone = venue_layout.zone_mut(&ticket.zone)?;
+        venue_zone.sold = venue_zone.sold
+            .checked_sub(1 + ticket.guest_slots as u32)
+            .unwrap();
+    }
+    require!(
+        ticket.seat.is_some() == ctx.accounts.seat.is_some(),
+        GatherFiError::InvalidSeat
+    );
+    ticket.seat = None;
+    
+    // Update event
+    let event = &mut ctx.accounts.event;
+    event.tickets_sold = event.tickets_sold.checked_sub(1).unwrap();
+    event.revenue_from_tickets = event.revenue_from_tickets.checked_sub(refund_amount).unwrap();
+    event.updated_at = clock.unix_timestamp;
+    
+    // Update profit pool
+    let profit_pool = &mut ctx.accounts.profit_pool;
+    profit_pool.total_revenue = profit_pool.total_revenue.checked_sub(refund_amount).unwrap();
+    profit_pool.updated_at = clock.unix_timestamp;
+    
+    msg!("↩️  Ticket #{} refunded for {}", ticket.ticket_number, event.name);
+    msg!("💰 Amount: {}", refund_amount);
+    if ticket.guests_issued > 0 {
+        msg!("🍽️  {} guest ticket(s) voided", ticket.guests_issued);
+    }
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct RegisterVendor<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Vendor::SIZE,
+        seeds = [b"vendor", authority.key().as_ref()],
+        bump
+    )]
+    pub vendor: Account<'info, Vendor>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl Vendor {
+    pub const SIZE: usize = 32 + 32 + 4 + MAX_VENDOR_NAME_LEN + 1 + 8 + 8 + 1;
+}
+
+pub fn handler(
+    ctx: Context<RegisterVendor>,
+    name: String,
+    payout_wallet: Pubkey,
+) -> Result<()> {
+    let vendor = &mut ctx.accounts.vendor;
+    let clock = Clock::get()?;
+    
+    require!(
+        !name.is_empty() && name.len() <= MAX_VENDOR_NAME_LEN,
+        GatherFiError::InvalidVendor
+    );
+    
+    vendor.authority = ctx.accounts.authority.key();
+    vendor.payout_wallet = payout_wallet;
+    vendor.name = name;
+    vendor.status = VendorStatus::Unverified;
+    vendor.created_at = clock.unix_timestamp;
+    vendor.updated_at = clock.unix_timestamp;
+    vendor.bump = ctx.bumps.vendor;
+    
+    msg!("🏪 Vendor registered: {}", vendor.name);
+    msg!("💳 Payout wallet: {}", payout_wallet);
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::GatherFiError;
+use crate::payments;
+
+#[derive(Accounts)]
+#[instruction(milestone_index: u8)]
+pub struct ReleaseMilestone<'info> {
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        has_one = organizer @ GatherFiError::NotOrganizer,
+        has_one = escrow,
+        has_one = budget,
+        constraint = event.status == EventStatus::Funded @ GatherFiError::EventNotFunded,
+        constraint = !event.is_paused @ GatherFiError::EventPaused,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        constraint = budget.approved_version > 0 @ GatherFiError::BudgetNotApproved,
+    )]
+    pub budget: Account<'info, Budget>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", event.key().as_ref()],
+        bump = escrow.bump,
+        constraint = !escrow.is_locked @ GatherFiError::EscrowLocked,
+        constraint = escrow.open_disputes == 0 @ GatherFiError::EscrowDisputed,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    // Required when the milestone has requires_vote
+    #[account(
+        seeds = [b"milestone_proposal", escrow.key().as_ref(), &[milestone_index]],
+        bump = milestone_proposal.bump
+    )]
+    pub milestone_proposal: Option<Account<'info, MilestoneProposal>>,
+    
+    // Escrow only ever pays out to verified vendors named in the approved budget,
+    // never the organizer
+    #[account(
+        constraint = vendor.status == VendorStatus::Verified @ GatherFiError::VendorNotVerified,
+        constraint = budget.items.iter().any(|item| item.vendor == vendor.key()) @ GatherFiError::InvalidVendor,
+    )]
+    pub vendor: Account<'info, Vendor>,
+    
+    #[account(
+        mut,
+        address = vendor.payout_wallet @ GatherFiError::InvalidVendor,
+    )]
+    pub vendor_wallet: SystemAccount<'info>,
+    
+    // Token accounts, required when the event has a payment mint
+    #[account(
+        mut,
+        constraint = vendor_payment_account.owner == vendor.payout_wallet @ GatherFiError::InvalidVendor,
+    )]
+    pub vendor_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", event.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+pub fn handler(
+    ctx: Context<ReleaseMilestone>,
+    milestone_index: u8,
+    amount: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    // Validate milestone
+    let escrow = &ctx.accounts.escrow;
+    let milestone = escrow.milestones
+        .get(milestone_index as usize)
+        .ok_or(GatherFiError::InvalidMilestone)?;
+    require!(!milestone.is_released, GatherFiError::MilestoneAlreadyReleased);
+    require!(amount == milestone.amount, GatherFiError::InvalidMilestoneAmount);
+    require!(clock.unix_timestamp >= milestone.due_date, GatherFiError::MilestoneNotDue);
+    
+    // A later budget version may have lowered the cap below what was scheduled
+    let released = escrow.released_amount.checked_add(amount).unwrap();
+    require!(released <= escrow.milestone_cap, GatherFiError::MilestoneExceedsBudget);
+    if escrow.requires_approval {
+        require!(
+            milestone.approval_count >= escrow.approvals_needed,
+            GatherFiError::InsufficientApprovals
+        );
+    }
+    if milestone.requires_vote {
+        let passed = ctx.accounts.milestone_proposal
+            .as_ref()
+            .is_some_and(|proposal| proposal.has_passed(clock.unix_timestamp));
+        require!(passed, GatherFiError::MilestoneVoteNotPassed);
+    }
+    
+    // Pay the vendor from escrow
+    let route = payments::token_route(
+        &ctx.accounts.event,
+        ctx.accounts.vendor_payment_account.as_ref(),
+        ctx.accounts.escrow_vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    )?;
+    
+    payments::disburse(
+        &ctx.accounts.escrow,
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.vendor_wallet.to_account_info(),
+        route,
+        amount,
+    )?;
+    
+    // Update escrow
+    let escrow = &mut ctx.accounts.escrow;
+    let milestone = &mut escrow.milestones[milestone_index as usize];
+    milestone.is_released = true;
+    milestone.released_at = Some(clock.unix_timestamp);
+    milestone.released_by = Some(ctx.accounts.organizer.key());
+    
+    escrow.released_amount = escrow.released_amount.checked_add(amount).unwrap();
+    escrow.balance = escrow.balance.checked_sub(amount).unwrap();
+    escrow.current_milestone = milestone_index.checked_add(1).unwrap();
+    
+    // Update budget
+    let budget = &mut ctx.accounts.budget;
+    budget.amount_spent = budget.amount_spent.checked_add(amount).unwrap();
+    budget.amount_remaining = budget.amount_remaining.saturating_sub(amount);
+    budget.updated_at = clock.unix_timestamp;
+    
+    msg!("🔓 Milestone #{} released for {}", milestone_index, ctx.accounts.event.name);
+    msg!("💸 Paid {} to {}", amount, ctx.accounts.vendor.name);
+    msg!("🏦 Escrow balance: {}", escrow.balance);
+    
+    Ok(())
+}
\ No newline at end of file
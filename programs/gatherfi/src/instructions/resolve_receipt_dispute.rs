@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct ResolveReceiptDispute<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = admin @ GatherFiError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        has_one = escrow,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", event.key().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        mut,
+        has_one = event,
+        constraint = receipt.is_flagged @ GatherFiError::ReceiptNotFlagged,
+    )]
+    pub receipt: Account<'info, Receipt>,
+}
+
+pub fn handler(ctx: Context<ResolveReceiptDispute>, upheld: bool) -> Result<()> {
+    let receipt = &mut ctx.accounts.receipt;
+    let escrow = &mut ctx.accounts.escrow;
+    
+    receipt.is_flagged = false;
+    receipt.is_resolved = true;
+    receipt.dispute_upheld = upheld;
+    escrow.open_disputes = escrow.open_disputes.checked_sub(1).unwrap();
+    
+    // Spending the admin ruled against locks escrow for good. Backers can still wind
+    // the event down through a no-confidence vote to recover what's left
+    if upheld {
+        escrow.is_locked = true;
+    }
+    
+    msg!(
+        "⚖️  Dispute on receipt {:?} {}",
+        receipt.target,
+        if upheld { "upheld" } else { "dismissed" }
+    );
+    if upheld {
+        msg!("🔒 Escrow locked, no further releases");
+    } else if escrow.open_disputes == 0 {
+        msg!("🔓 Escrow releases unfrozen");
+    }
+    
+    Ok(())
+}
\ No newline at end of file
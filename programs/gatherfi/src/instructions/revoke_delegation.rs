@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+    
+    // Votes already cast by the delegate stand
+    #[account(
+        mut,
+        close = delegator,
+        has_one = delegator,
+        seeds = [b"delegation", delegator.key().as_ref(), delegation.event.unwrap_or_default().as_ref()],
+        bump = delegation.bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+}
+
+pub fn handler(ctx: Context<RevokeDelegation>) -> Result<()> {
+    let delegation = &ctx.accounts.delegation;
+    
+    msg!(
+        "✂️  {} revoked delegation to {}",
+        delegation.delegator,
+        delegation.delegate
+    );
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct SetBudgetCaps<'info> {
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        has_one = organizer @ GatherFiError::NotOrganizer,
+        has_one = budget,
+    )]
+    pub event: Account<'info, Event>,
+    
+    // Caps are fixed once backers have seen a budget
+    #[account(
+        mut,
+        constraint = budget.version == 0 @ GatherFiError::BudgetPolicyLocked,
+    )]
+    pub budget: Account<'info, Budget>,
+}
+
+pub fn handler(ctx: Context<SetBudgetCaps>, caps: Vec<CategoryCap>) -> Result<()> {
+    let budget = &mut ctx.accounts.budget;
+    let clock = Clock::get()?;
+    
+    CategoryCap::validate(&caps)?;
+    
+    budget.category_caps = caps;
+    budget.updated_at = clock.unix_timestamp;
+    
+    msg!(
+        "📏 {} budget cap(s) set for {}",
+        budget.category_caps.len(),
+        ctx.accounts.event.name
+    );
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+#[instruction(category: EventCategory)]
+pub struct SetBudgetPolicy<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = admin @ GatherFiError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + BudgetPolicy::SIZE,
+        seeds = [b"budget_policy".as_ref(), &category.seed()],
+        bump
+    )]
+    pub budget_policy: Account<'info, BudgetPolicy>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl BudgetPolicy {
+    pub const SIZE: usize = 1 + 4 + MAX_CATEGORY_CAPS * CategoryCap::SIZE + 8 + 1;
+}
+
+pub fn handler(
+    ctx: Context<SetBudgetPolicy>,
+    category: EventCategory,
+    caps: Vec<CategoryCap>,
+) -> Result<()> {
+    let budget_policy = &mut ctx.accounts.budget_policy;
+    let clock = Clock::get()?;
+    
+    CategoryCap::validate(&caps)?;
+    
+    // Applies to budgets submitted from now on
+    budget_policy.category = category;
+    budget_policy.caps = caps;
+    budget_policy.updated_at = clock.unix_timestamp;
+    budget_policy.bump = ctx.bumps.budget_policy;
+    
+    msg!("📏 Budget policy set with {} cap(s)", budget_policy.caps.len());
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct SetTransferPolicy<'info> {
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = organizer @ GatherFiError::NotOrganizer,
+        constraint = event.status != EventStatus::Cancelled @ GatherFiError::EventNotActive,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        init_if_needed,
+        payer = organizer,
+        space = 8 + TransferPolicy::SIZE,
+        seeds = [b"transfer_policy", event.key().as_ref()],
+        bump
+    )]
+    pub transfer_policy: Account<'info, TransferPolicy>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl TransferPolicy {
+    pub const SIZE: usize = 32 + 1 + 1 + 8 + 8 + 1;
+}
+
+pub fn handler(
+    ctx: Context<SetTransferPolicy>,
+    transfers_enabled: bool,
+    max_transfers: u8,
+    cutoff: i64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let transfer_policy = &mut ctx.accounts.transfer_policy;
+    let clock = Clock::get()?;
+    
+    require!(cutoff >= 0, GatherFiError::InvalidTransferPolicy);
+    
+    // Applies to every later transfer, including tickets already sold
+    transfer_policy.event = event.key();
+    transfer_policy.transfers_enabled = transfers_enabled;
+    transfer_policy.max_transfers = max_transfers;
+    transfer_policy.cutoff = cutoff;
+    transfer_policy.updated_at = clock.unix_timestamp;
+    transfer_policy.bump = ctx.bumps.transfer_policy;
+    
+    event.transfer_policy = Some(transfer_policy.key());
+    event.updated_at = clock.unix_timestamp;
+    
+    msg!("🔁 Transfer policy set for {}", event.name);
+    msg!(
+        "✅ Enabled: {} | Max transfers: {} | Cutoff: {}s before the event",
+        transfers_enabled,
+        max_transfers,
+        cutoff
+    );
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct SetVenueLayout<'info> {
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = organizer @ GatherFiError::NotOrganizer,
+        constraint = event.status != EventStatus::Cancelled @ GatherFiError::EventNotActive,
+        constraint = event.tickets_sold == 0 @ GatherFiError::VenueLayoutLocked,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        init_if_needed,
+        payer = organizer,
+        space = 8 + VenueLayout::SIZE,
+        seeds = [b"venue_layout", event.key().as_ref()],
+        bump
+    )]
+    pub venue_layout: Account<'info, VenueLayout>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl VenueZone {
+    pub const SIZE: usize = 4 + MAX_ZONE_NAME_LEN + 4 + 1 + 2 + 4;
+}
+
+impl VenueLayout {
+    pub const SIZE: usize = 32 + 4 + MAX_VENUE_ZONES * VenueZone::SIZE + 8 + 8 + 1;
+}
+
+pub fn handler(ctx: Context<SetVenueLayout>, zones: Vec<VenueZone>) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let venue_layout = &mut ctx.accounts.venue_layout;
+    let clock = Clock::get()?;
+    
+    VenueLayout::validate(&zones, event.max_tickets)?;
+    
+    if venue_layout.event == Pubkey::default() {
+        venue_layout.event = event.key();
+        venue_layout.created_at = clock.unix_timestamp;
+        venue_layout.bump = ctx.bumps.venue_layout;
+    }
+    
+    // Nothing is sold yet, so every zone starts empty
+    venue_layout.zones = zones
+        .into_iter()
+        .map(|zone| VenueZone { sold: 0, ..zone })
+        .collect();
+    venue_layout.updated_at = clock.unix_timestamp;
+    
+    event.venue_layout = Some(venue_layout.key());
+    event.updated_at = clock.unix_timestamp;
+    
+    msg!("🏟️  Venue layout set for {}", event.name);
+    for zone in venue_layout.zones.iter() {
+        msg!("📍 {}: {} place(s), {} row(s)", zone.name, zone.capacity, zone.rows);
+    }
+    
+    Ok(())
+}
\ No newline at end of file
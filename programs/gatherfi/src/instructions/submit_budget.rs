@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct SubmitBudget<'info> {
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = organizer @ GatherFiError::NotOrganizer,
+        has_one = budget,
+        constraint = matches!(event.status, EventStatus::Funding | EventStatus::Funded) @ GatherFiError::EventNotActive,
+        constraint = !event.is_paused @ GatherFiError::EventPaused,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        seeds = [b"budget", event.key().as_ref()],
+        bump = budget.bump,
+    )]
+    pub budget: Account<'info, Budget>,
+    
+    // Archives the outgoing version, required on every resubmission
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + BudgetRevision::SIZE,
+        seeds = [b"budget_revision", budget.key().as_ref(), &budget.version.to_le_bytes()],
+        bump
+    )]
+    pub budget_revision: Option<Account<'info, BudgetRevision>>,
+    
+    /// CHECK: Caps for the event's category. Uninitialized when the admin hasn't set a
+    /// policy, so it can't be an Account, but the seeds stop callers swapping it out
+    #[account(
+        seeds = [b"budget_policy".as_ref(), &event.category.seed()],
+        bump
+    )]
+    pub budget_policy: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl BudgetRevision {
+    pub const SIZE: usize = 32 + 2 + 2000 + 8 + 8 + 8 + 4 + 8 + 1 + 8 + 8 + 1;
+}
+
+pub fn handler(
+    ctx: Context<SubmitBudget>,
+    mut budget_items: Vec<BudgetItem>,
+    total_amount: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let budget = &mut ctx.accounts.budget;
+    let clock = Clock::get()?;
+    
+    require!(
+        !budget.is_voting_open(clock.unix_timestamp),
+        GatherFiError::BudgetVoteInProgress
+    );
+    
+    let items_total = budget_items
+        .iter()
+        .try_fold(0u64, |sum, item| sum.checked_add(item.amount))
+        .ok_or(GatherFiError::InvalidBudget)?;
+    require!(
+        !budget_items.is_empty() && items_total == total_amount && total_amount >= budget.amount_spent,
+        GatherFiError::InvalidBudget
+    );
+    
+    // Category-wide policy first, then the organizer's own caps
+    let budget_policy = &ctx.accounts.budget_policy;
+    if !budget_policy.data_is_empty() {
+        require_keys_eq!(*budget_policy.owner, crate::ID, GatherFiError::InvalidBudgetPolicy);
+        let budget_policy = BudgetPolicy::try_deserialize(&mut &budget_policy.try_borrow_data()?[..])?;
+        CategoryCap::check(&budget_policy.caps, &budget_items, total_amount)?;
+    }
+    CategoryCap::check(&budget.category_caps, &budget_items, total_amount)?;
+    
+    // Only pay_budget_item marks items paid. Items already paid keep their index and
+    // terms in every later version, and carry their paid state forward
+    for item in budget_items.iter_mut() {
+        item.is_paid = false;
+        item.paid_at = None;
+        item.paid_version = 0;
+        item.has_receipt = false;
+    }
+    for (index, paid_item) in budget.items.iter().enumerate().filter(|(_, item)| item.is_paid) {
+        let item = budget_items
+            .get_mut(index)
+            .ok_or(GatherFiError::BudgetItemAlreadyPaid)?;
+        require!(
+            item.name == paid_item.name
+                && item.vendor == paid_item.vendor
+                && item.amount == paid_item.amount
+                && item.category == paid_item.category,
+            GatherFiError::BudgetItemAlreadyPaid
+        );
+        item.is_paid = true;
+        item.paid_at = paid_item.paid_at;
+        item.paid_version = paid_item.paid_version;
+        item.has_receipt = paid_item.has_receipt;
+    }
+    
+    // Archive the outgoing version with its vote result. The approved version, if any,
+    // stays in force until the new one is approved
+    if budget.version > 0 {
+        let budget_revision = ctx.accounts.budget_revision
+            .as_mut()
+            .ok_or(GatherFiError::MissingBudgetRevision)?;
+        budget_revision.budget = budget.key();
+        budget_revision.version = budget.version;
+        budget_revision.items = std::mem::take(&mut budget.items);
+        budget_revision.total_amount = budget.total_amount;
+        budget_revision.votes_for = budget.votes_for;
+        budget_revision.votes_against = budget.votes_against;
+        budget_revision.total_voters = budget.total_voters;
+        budget_revision.snapshot_voting_power = budget.snapshot_voting_power;
+        budget_revision.was_approved = budget.is_approved;
+        budget_revision.submitted_at = budget.snapshot_at;
+        budget_revision.superseded_at = clock.unix_timestamp;
+        budget_revision.bump = ctx.bumps.budget_revision.ok_or(GatherFiError::MissingBudgetRevision)?;
+    }
+    
+    budget.version = budget.version.checked_add(1).unwrap();
+    budget.items = budget_items;
+    budget.total_amount = total_amount;
+    
+    // Open voting. Backers vote with their power as of now, later top-ups don't count
+    budget.is_approved = false;
+    budget.votes_for = 0;
+    budget.votes_against = 0;
+    budget.total_voters = 0;
+    budget.voting_ends_at = clock.unix_timestamp + budget.voting_period;
+    budget.snapshot_at = clock.unix_timestamp;
+    budget.snapshot_voting_power = event.total_voting_weight;
+    budget.snapshot_backers = event.total_backers;
+    budget.finalized_at = 0;
+    budget.is_locked = false;
+    budget.updated_at = clock.unix_timestamp;
+    
+    event.voting_snapshot_at = clock.unix_timestamp;
+    event.updated_at = clock.unix_timestamp;
+    
+    msg!("📋 Budget v{} submitted for {}", budget.version, event.name);
+    msg!("💰 Total: {} across {} items", total_amount, budget.items.len());
+    msg!(
+        "🗳️  {:?} voting, {} votes from {} backers | Ends: {}",
+        event.voting_mode,
+        budget.snapshot_voting_power,
+        budget.snapshot_backers,
+        budget.voting_ends_at
+    );
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    transfer_hook::TransferHookAccount,
+    BaseStateWithExtensionsMut,
+    PodStateWithExtensionsMut,
+};
+use anchor_spl::token_2022::spl_token_2022::pod::PodAccount;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+// Called by Token-2022 on every transfer of a hooked ticket mint. The first four
+// accounts are fixed by the transfer hook interface; the rest are resolved from
+// the extra account metas written by mint_hooked_ticket
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Owner or delegate of the source account, already verified by Token-2022
+    pub owner: UncheckedAccount<'info>,
+    
+    /// CHECK: Validation account read by Token-2022 to resolve the accounts below
+    #[account(
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        has_one = event,
+        has_one = mint,
+    )]
+    pub ticket: Account<'info, Ticket>,
+    
+    #[account(
+        constraint = !event.is_paused @ GatherFiError::EventPaused,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        seeds = [b"transfer_policy", event.key().as_ref()],
+        bump = transfer_policy.bump
+    )]
+    pub transfer_policy: Account<'info, TransferPolicy>,
+}
+
+pub fn handler(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+    // Only Token-2022 may invoke the hook, mid-transfer
+    check_is_transferring(&ctx)?;
+    require!(amount == 1, GatherFiError::InvalidTransferAmount);
+    
+    let event = &ctx.accounts.event;
+    let ticket = &mut ctx.accounts.ticket;
+    let clock = Clock::get()?;
+    
+    require_keys_eq!(
+        ctx.accounts.source_token.owner,
+        ticket.owner,
+        GatherFiError::NotTicketOwner
+    );
+    
+    ctx.accounts.transfer_policy.check(ticket, event.event_date, clock.unix_timestamp)?;
+    
+    ticket.owner = ctx.accounts.destination_token.owner;
+    ticket.is_transferred = true;
+    ticket.transfer_count = ticket.transfer_count.saturating_add(1);
+    
+    msg!("🔁 Ticket #{} transferred for {}", ticket.ticket_number, event.name);
+    msg!("👤 New owner: {}", ticket.owner);
+    
+    Ok(())
+}
+
+// Token-2022 flags the source account while a transfer is in progress
+fn check_is_transferring(ctx: &Context<TransferHook>) -> Result<()> {
+    let source_token_info = ctx.accounts.source_token.to_account_info();
+    let mut account_data_ref = source_token_info.try_borrow_mut_data()?;
+    let mut account = PodStateWithExtensionsMut::<PodAccount>::unpack(*account_data_ref)?;
+    let account_extension = account.get_extension_mut::<TransferHookAccount>()?;
+    
+    require!(
+        bool::from(account_extension.transferring),
+        GatherFiError::NotTransferring
+    );
+    
+    Ok(())
+}
\ No newline at end of file
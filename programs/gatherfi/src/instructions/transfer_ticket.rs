@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Mint, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+// Moves an SPL ticket and its ownership record together. Token-2022 tickets
+// are moved with a plain token transfer; the transfer hook keeps them in sync
+#[derive(Accounts)]
+#[instruction(new_owner: Pubkey)]
+pub struct TransferTicket<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        constraint = !event.is_paused @ GatherFiError::EventPaused,
+    )]
+    pub event: Account<'info, Event>,
+    
+    // Required once the organizer has set a transfer policy
+    #[account(
+        seeds = [b"transfer_policy", event.key().as_ref()],
+        bump = transfer_policy.bump
+    )]
+    pub transfer_policy: Option<Account<'info, TransferPolicy>>,
+    
+    #[account(
+        mut,
+        has_one = event,
+        has_one = owner @ GatherFiError::NotTicketOwner,
+    )]
+    pub ticket: Account<'info, Ticket>,
+    
+    #[account(address = ticket.mint)]
+    pub ticket_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = ticket_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Any wallet can receive a ticket
+    #[account(address = new_owner)]
+    pub recipient: UncheckedAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = ticket_mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<TransferTicket>, new_owner: Pubkey) -> Result<()> {
+    let event = &ctx.accounts.event;
+    let ticket = &mut ctx.accounts.ticket;
+    let clock = Clock::get()?;
+    
+    // Events without a policy keep the original free transfers
+    if event.transfer_policy.is_some() {
+        let transfer_policy = ctx.accounts.transfer_policy
+            .as_ref()
+            .ok_or(GatherFiError::TransferPolicyRequired)?;
+        transfer_policy.check(ticket, event.event_date, clock.unix_timestamp)?;
+    } else {
+        require!(!ticket.is_checked_in, GatherFiError::AlreadyCheckedIn);
+        require!(!ticket.is_refunded, GatherFiError::AlreadyRefunded);
+    }
+    
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.owner_token_account.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    
+    token::transfer(cpi_context, 1)?;
+    
+    ticket.owner = new_owner;
+    ticket.is_transferred = true;
+    ticket.transfer_count = ticket.transfer_count.saturating_add(1);
+    
+    msg!("🔁 Ticket #{} transferred for {}", ticket.ticket_number, event.name);
+    msg!("👤 New owner: {}", new_owner);
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct UpdatePlatformConfig<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = admin @ GatherFiError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdatePlatformConfig>,
+    new_admin: Option<Pubkey>,
+    min_contribution: Option<u64>,
+    funding_period: Option<i64>,
+    platform_fee: Option<u64>,
+    backer_share: Option<u64>,
+    organizer_share: Option<u64>,
+    budget_voting_period: Option<i64>,
+    governance_cutoff: Option<i64>,
+) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+    let clock = Clock::get()?;
+    
+    if let Some(new_admin) = new_admin {
+        platform_config.admin = new_admin;
+    }
+    if let Some(min_contribution) = min_contribution {
+        platform_config.min_contribution = min_contribution;
+    }
+    if let Some(funding_period) = funding_period {
+        platform_config.funding_period = funding_period;
+    }
+    if let Some(platform_fee) = platform_fee {
+        platform_config.platform_fee = platform_fee;
+    }
+    if let Some(backer_share) = backer_share {
+        platform_config.backer_share = backer_share;
+    }
+    if let Some(organizer_share) = organizer_share {
+        platform_config.organizer_share = organizer_share;
+    }
+    if let Some(budget_voting_period) = budget_voting_period {
+        platform_config.budget_voting_period = budget_voting_period;
+    }
+    if let Some(governance_cutoff) = governance_cutoff {
+        platform_config.governance_cutoff = governance_cutoff;
+    }
+    
+    // Rejects fees above MAX_PLATFORM_FEE and splits that don't sum to 100%
+    platform_config.validate()?;
+    platform_config.updated_at = clock.unix_timestamp;
+    
+    msg!("⚙️  Platform config updated");
+    msg!(
+        "💰 Split: backers {} / organizer {} / platform {} bps",
+        platform_config.backer_share,
+        platform_config.organizer_share,
+        platform_config.platform_fee
+    );
+    
+    Ok(())
+}
\ No newline at end of file
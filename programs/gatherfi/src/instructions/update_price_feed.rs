@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct UpdatePriceFeed<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = authority @ GatherFiError::Unauthorized,
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+}
+
+pub fn handler(
+    ctx: Context<UpdatePriceFeed>,
+    kobo_per_unit: u64,
+    max_staleness: Option<i64>,
+) -> Result<()> {
+    let price_feed = &mut ctx.accounts.price_feed;
+    let clock = Clock::get()?;
+    
+    require!(kobo_per_unit > 0, GatherFiError::InvalidPriceFeed);
+    
+    price_feed.kobo_per_unit = kobo_per_unit;
+    if let Some(max_staleness) = max_staleness {
+        require!(max_staleness > 0, GatherFiError::InvalidPriceFeed);
+        price_feed.max_staleness = max_staleness;
+    }
+    price_feed.updated_at = clock.unix_timestamp;
+    
+    msg!("💱 Rate updated: {} kobo per unit", kobo_per_unit);
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct VerifyVendor<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = admin @ GatherFiError::Unauthorized,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+    
+    #[account(mut)]
+    pub vendor: Account<'info, Vendor>,
+}
+
+pub fn handler(ctx: Context<VerifyVendor>, status: VendorStatus) -> Result<()> {
+    let vendor = &mut ctx.accounts.vendor;
+    let clock = Clock::get()?;
+    
+    vendor.status = status;
+    vendor.updated_at = clock.unix_timestamp;
+    
+    msg!("🏪 Vendor {} is now {:?}", vendor.name, status);
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct VoteNoConfidence<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = escrow,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        seeds = [b"no_confidence", event.key().as_ref()],
+        bump = no_confidence_proposal.bump,
+    )]
+    pub no_confidence_proposal: Account<'info, NoConfidenceProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"escrow", event.key().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(
+        seeds = [b"contribution", event.key().as_ref(), voter.key().as_ref()],
+        bump = contribution.bump,
+        constraint = contribution.voting_power > 0 @ GatherFiError::InsufficientVotingPower,
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    // One vote per (proposal, round, backer)
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + NoConfidenceVote::SIZE,
+        seeds = [
+            b"no_confidence_vote",
+            no_confidence_proposal.key().as_ref(),
+            &[no_confidence_proposal.round],
+            voter.key().as_ref(),
+        ],
+        bump
+    )]
+    pub no_confidence_vote: Account<'info, NoConfidenceVote>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl NoConfidenceVote {
+    pub const SIZE: usize = 32 + 32 + 8 + 1 + 8 + 1;
+}
+
+pub fn handler(ctx: Context<VoteNoConfidence>, approve: bool) -> Result<()> {
+    let proposal = &mut ctx.accounts.no_confidence_proposal;
+    let vote = &mut ctx.accounts.no_confidence_vote;
+    let escrow = &mut ctx.accounts.escrow;
+    let voting_power = ctx.accounts.contribution.voting_power;
+    let clock = Clock::get()?;
+    
+    require!(
+        !proposal.passed && clock.unix_timestamp < proposal.voting_ends_at,
+        GatherFiError::VotingEnded
+    );
+    
+    // Tally
+    if approve {
+        proposal.votes_for = proposal.votes_for.checked_add(voting_power).unwrap();
+    } else {
+        proposal.votes_against = proposal.votes_against.checked_add(voting_power).unwrap();
+    }
+    proposal.total_voters = proposal.total_voters.checked_add(1).unwrap();
+    
+    // Record vote
+    vote.voter = ctx.accounts.voter.key();
+    vote.proposal = proposal.key();
+    vote.amount = voting_power;
+    vote.approve = approve;
+    vote.voted_at = clock.unix_timestamp;
+    vote.bump = ctx.bumps.no_confidence_vote;
+    
+    msg!(
+        "🗳️  {} voted {} no confidence with {} power",
+        ctx.accounts.voter.key(),
+        if approve { "FOR" } else { "AGAINST" },
+        voting_power
+    );
+    
+    // Wind down: freeze escrow, set aside what's left for pro-rata refunds and end
+    // ticket sales and profit distribution for good
+    if proposal.votes_for > proposal.threshold {
+        proposal.passed = true;
+        ctx.accounts.event.transition_to(EventStatus::WoundDown)?;
+        escrow.is_locked = true;
+        escrow.is_wound_down = true;
+        escrow.refund_pool = escrow.balance;
+    
+        msg!("🛑 No confidence passed, {} is wound down", ctx.accounts.event.name);
+        msg!("↩️  {} refundable to backers", escrow.refund_pool);
+    }
+    
+    Ok(())
+}
\ No newline at end of file
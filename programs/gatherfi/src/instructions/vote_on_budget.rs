@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct VoteOnBudget<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    #[account(
+        mut,
+        has_one = budget,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(mut)]
+    pub budget: Account<'info, Budget>,
+    
+    // The backer whose power is cast, the voter themselves or someone who delegated to them
+    #[account(
+        seeds = [b"contribution", event.key().as_ref(), contribution.contributor.as_ref()],
+        bump = contribution.bump,
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    // Required when voting with someone else's contribution
+    pub delegation: Option<Account<'info, Delegation>>,
+    
+    /// CHECK: The backer's delegation for this event, if any. Required with an all-events
+    /// delegation and must be empty, since a per-event delegation overrides it
+    #[account(
+        seeds = [b"delegation", contribution.contributor.as_ref(), event.key().as_ref()],
+        bump
+    )]
+    pub event_delegation: Option<UncheckedAccount<'info>>,
+    
+    // One vote per (budget version, backer), whoever casts it, so delegated power
+    // can't be counted twice
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = 8 + Vote::SIZE,
+        seeds = [b"vote", budget.key().as_ref(), &budget.version.to_le_bytes(), contribution.contributor.as_ref()],
+        bump
+    )]
+    pub vote: Account<'info, Vote>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl Vote {
+    pub const SIZE: usize = 32 + 32 + 32 + 32 + 2 + 8 + 1 + 8 + 1;
+}
+
+pub fn handler(ctx: Context<VoteOnBudget>, approve: bool) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let budget = &mut ctx.accounts.budget;
+    let vote = &mut ctx.accounts.vote;
+    let clock = Clock::get()?;
+    
+    require!(budget.snapshot_at > 0, GatherFiError::BudgetNotSubmitted);
+    require!(budget.is_voting_open(clock.unix_timestamp), GatherFiError::VotingEnded);
+    require!(vote.voter == Pubkey::default(), GatherFiError::AlreadyVoted);
+    
+    let backer = ctx.accounts.contribution.contributor;
+    let voter = ctx.accounts.voter.key();
+    if backer != voter {
+        let delegation = ctx.accounts.delegation
+            .as_ref()
+            .filter(|delegation| delegation.covers(backer, voter, event.key()))
+            .ok_or(GatherFiError::NotDelegate)?;
+        
+        if delegation.event.is_none() {
+            let event_delegation = ctx.accounts.event_delegation
+                .as_ref()
+                .ok_or(GatherFiError::NotDelegate)?;
+            require!(event_delegation.data_is_empty(), GatherFiError::DelegationOverridden);
+        }
+    }
+    
+    // Power as of budget submission, ignoring later contributions, weighted by voting mode
+    let voting_power = ctx.accounts.contribution.voting_power_at(budget.snapshot_at);
+    require!(voting_power > 0, GatherFiError::InsufficientVotingPower);
+    let weight = event.vote_weight(voting_power);
+    
+    // Tally
+    if approve {
+        budget.votes_for = budget.votes_for.checked_add(weight).unwrap();
+        event.votes_for = event.votes_for.checked_add(weight).unwrap();
+    } else {
+        budget.votes_against = budget.votes_against.checked_add(weight).unwrap();
+        event.votes_against = event.votes_against.checked_add(weight).unwrap();
+    }
+    budget.total_voters = budget.total_voters.checked_add(1).unwrap();
+    event.total_votes = event.total_votes.checked_add(weight).unwrap();
+    budget.updated_at = clock.unix_timestamp;
+    
+    // Record vote
+    vote.voter = backer;
+    vote.cast_by = voter;
+    vote.budget = budget.key();
+    vote.event = event.key();
+    vote.version = budget.version;
+    vote.amount = weight;
+    vote.approve = approve;
+    vote.voted_at = clock.unix_timestamp;
+    vote.bump = ctx.bumps.vote;
+    
+    msg!(
+        "🗳️  {} voted {} on the budget with {} votes ({:?})",
+        backer,
+        if approve { "FOR" } else { "AGAINST" },
+        weight,
+        event.voting_mode
+    );
+    if backer != voter {
+        msg!("🤝 Cast by delegate {}", voter);
+    }
+    msg!("📊 For: {} | Against: {}", budget.votes_for, budget.votes_against);
+    if budget.vote_outcome(clock.unix_timestamp).is_some() {
+        msg!("🏁 Outcome decided, ready for finalize_budget_vote");
+    }
+    
+    Ok(())
+}
\ No newline at end of file
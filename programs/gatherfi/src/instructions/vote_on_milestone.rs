@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+#[derive(Accounts)]
+pub struct VoteOnMilestone<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    #[account(
+        constraint = event.status == EventStatus::Funded @ GatherFiError::EventNotFunded,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        has_one = event,
+    )]
+    pub milestone_proposal: Account<'info, MilestoneProposal>,
+    
+    #[account(
+        seeds = [b"contribution", event.key().as_ref(), voter.key().as_ref()],
+        bump = contribution.bump,
+        constraint = contribution.voting_power > 0 @ GatherFiError::InsufficientVotingPower,
+    )]
+    pub contribution: Account<'info, Contribution>,
+    
+    // One vote per (proposal, round, backer)
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + MilestoneVote::SIZE,
+        seeds = [
+            b"milestone_vote",
+            milestone_proposal.key().as_ref(),
+            &[milestone_proposal.round],
+            voter.key().as_ref(),
+        ],
+        bump
+    )]
+    pub milestone_vote: Account<'info, MilestoneVote>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+impl MilestoneVote {
+    pub const SIZE: usize = 32 + 32 + 8 + 1 + 8 + 1;
+}
+
+pub fn handler(ctx: Context<VoteOnMilestone>, approve: bool) -> Result<()> {
+    let milestone_proposal = &mut ctx.accounts.milestone_proposal;
+    let milestone_vote = &mut ctx.accounts.milestone_vote;
+    let voting_power = ctx.accounts.contribution.voting_power;
+    let clock = Clock::get()?;
+    
+    require!(
+        clock.unix_timestamp < milestone_proposal.voting_ends_at,
+        GatherFiError::VotingEnded
+    );
+    
+    // Tally
+    if approve {
+        milestone_proposal.votes_for = milestone_proposal.votes_for.checked_add(voting_power).unwrap();
+    } else {
+        milestone_proposal.votes_against = milestone_proposal.votes_against.checked_add(voting_power).unwrap();
+    }
+    milestone_proposal.total_voters = milestone_proposal.total_voters.checked_add(1).unwrap();
+    
+    // Record vote
+    milestone_vote.voter = ctx.accounts.voter.key();
+    milestone_vote.proposal = milestone_proposal.key();
+    milestone_vote.amount = voting_power;
+    milestone_vote.approve = approve;
+    milestone_vote.voted_at = clock.unix_timestamp;
+    milestone_vote.bump = ctx.bumps.milestone_vote;
+    
+    msg!(
+        "🗳️  {} voted {} on milestone #{} with {} power",
+        ctx.accounts.voter.key(),
+        if approve { "FOR" } else { "AGAINST" },
+        milestone_proposal.milestone_index,
+        voting_power
+    );
+    msg!(
+        "📊 For: {} | Against: {}",
+        milestone_proposal.votes_for,
+        milestone_proposal.votes_against
+    );
+    
+    Ok(())
+}
\ No newline at end of file
@@ -1,10 +1,15 @@
 use anchor_lang::prelude::*;
 use instructions::*;
+use spl_discriminator::SplDiscriminate;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
 
 pub mod constants;
 pub mod errors;
 pub mod instructions;
+pub mod metadata;
+pub mod payments;
 pub mod state;
+pub mod tickets;
 
 declare_id!("GATHRFi1111111111111111111111111111111111111");
 
@@ -12,7 +17,7 @@ declare_id!("GATHRFi1111111111111111111111111111111111111");
 pub mod gatherfi {
     use super::*;
 
-    // ========== EVENT MANAGEMENT (3) ==========
+    // ========== EVENT MANAGEMENT (4) ==========
     pub fn create_event(
         ctx: Context<CreateEvent>,
         name: String,
@@ -23,6 +28,8 @@ pub mod gatherfi {
         event_date: i64,
         location: String,
         category: EventCategory,
+        priced_in_naira: bool,
+        voting_mode: VotingMode,
     ) -> Result<()> {
         instructions::create_event::handler(
             ctx,
@@ -34,6 +41,8 @@ pub mod gatherfi {
             event_date,
             location,
             category,
+            priced_in_naira,
+            voting_mode,
         )
     }
 
@@ -51,6 +60,14 @@ pub mod gatherfi {
         instructions::cancel_event::handler(ctx)
     }
 
+    pub fn configure_profit_split(
+        ctx: Context<ConfigureProfitSplit>,
+        backer_share: u64,
+        organizer_share: u64,
+    ) -> Result<()> {
+        instructions::configure_profit_split::handler(ctx, backer_share, organizer_share)
+    }
+
     // ========== CROWDFUNDING (3) ==========
     pub fn contribute(
         ctx: Context<Contribute>,
@@ -67,13 +84,60 @@ pub mod gatherfi {
         instructions::refund_contribution::handler(ctx)
     }
 
-    // ========== NFT TICKETING (4) ==========
+    // ========== NFT TICKETING (15) ==========
+    pub fn configure_ticket_tier(
+        ctx: Context<ConfigureTicketTier>,
+        ticket_type: TicketType,
+        price: u64,
+        supply: u32,
+        guests_per_ticket: u8,
+        is_active: bool,
+    ) -> Result<()> {
+        instructions::configure_ticket_tier::handler(
+            ctx,
+            ticket_type,
+            price,
+            supply,
+            guests_per_ticket,
+            is_active,
+        )
+    }
+
     pub fn mint_ticket(
         ctx: Context<MintTicket>,
         ticket_type: TicketType,
         zone: String,
+        seat_position: Option<SeatPosition>,
     ) -> Result<()> {
-        instructions::mint_ticket::handler(ctx, ticket_type, zone)
+        instructions::mint_ticket::handler(ctx, ticket_type, zone, seat_position)
+    }
+
+    pub fn mint_group_tickets(
+        ctx: Context<MintGroupTickets>,
+        recipients: Vec<Pubkey>,
+        zone: String,
+    ) -> Result<()> {
+        instructions::mint_group_tickets::handler(ctx, recipients, zone)
+    }
+
+    pub fn issue_group_ticket(ctx: Context<IssueGroupTicket>) -> Result<()> {
+        instructions::issue_group_ticket::handler(ctx)
+    }
+
+    pub fn issue_table_guest(ctx: Context<IssueTableGuest>) -> Result<()> {
+        instructions::issue_table_guest::handler(ctx)
+    }
+
+    pub fn create_ticket_collection(ctx: Context<CreateTicketCollection>) -> Result<()> {
+        instructions::create_ticket_collection::handler(ctx)
+    }
+
+    pub fn set_venue_layout(ctx: Context<SetVenueLayout>, zones: Vec<VenueZone>) -> Result<()> {
+        instructions::set_venue_layout::handler(ctx, zones)
+    }
+
+    pub fn assign_seat(ctx: Context<AssignSeat>, position: SeatPosition) -> Result<()> {
+        instructions::assign_seat::handler(ctx, position)
     }
 
     pub fn transfer_ticket(
@@ -83,15 +147,43 @@ pub mod gatherfi {
         instructions::transfer_ticket::handler(ctx, new_owner)
     }
 
+    pub fn set_transfer_policy(
+        ctx: Context<SetTransferPolicy>,
+        transfers_enabled: bool,
+        max_transfers: u8,
+        cutoff: i64,
+    ) -> Result<()> {
+        instructions::set_transfer_policy::handler(ctx, transfers_enabled, max_transfers, cutoff)
+    }
+
+    pub fn mint_hooked_ticket(
+        ctx: Context<MintHookedTicket>,
+        ticket_type: TicketType,
+        zone: String,
+        seat_position: Option<SeatPosition>,
+    ) -> Result<()> {
+        instructions::mint_hooked_ticket::handler(ctx, ticket_type, zone, seat_position)
+    }
+
+    pub fn issue_hooked_table_guest(ctx: Context<IssueHookedTableGuest>) -> Result<()> {
+        instructions::issue_hooked_table_guest::handler(ctx)
+    }
+
+    // Invoked by Token-2022 through the transfer hook interface's Execute instruction
+    #[instruction(discriminator = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+        instructions::transfer_hook::handler(ctx, amount)
+    }
+
     pub fn check_in(ctx: Context<CheckIn>) -> Result<()> {
         instructions::check_in::handler(ctx)
     }
 
-    pub fn refund_ticket(ctx: Context<RefundTicket>) -> Result<()> {
+    pub fn refund_ticket<'info>(ctx: Context<'_, '_, 'info, 'info, RefundTicket<'info>>) -> Result<()> {
         instructions::refund_ticket::handler(ctx)
     }
 
-    // ========== BUDGET & GOVERNANCE (3) ==========
+    // ========== BUDGET & GOVERNANCE (13) ==========
     pub fn submit_budget(
         ctx: Context<SubmitBudget>,
         budget_items: Vec<BudgetItem>,
@@ -107,6 +199,22 @@ pub mod gatherfi {
         instructions::vote_on_budget::handler(ctx, approve)
     }
 
+    pub fn finalize_budget_vote(ctx: Context<FinalizeBudgetVote>) -> Result<()> {
+        instructions::finalize_budget_vote::handler(ctx)
+    }
+
+    pub fn set_budget_policy(
+        ctx: Context<SetBudgetPolicy>,
+        category: EventCategory,
+        caps: Vec<CategoryCap>,
+    ) -> Result<()> {
+        instructions::set_budget_policy::handler(ctx, category, caps)
+    }
+
+    pub fn set_budget_caps(ctx: Context<SetBudgetCaps>, caps: Vec<CategoryCap>) -> Result<()> {
+        instructions::set_budget_caps::handler(ctx, caps)
+    }
+
     pub fn release_milestone(
         ctx: Context<ReleaseMilestone>,
         milestone_index: u8,
@@ -115,6 +223,38 @@ pub mod gatherfi {
         instructions::release_milestone::handler(ctx, milestone_index, amount)
     }
 
+    pub fn open_milestone_vote(ctx: Context<OpenMilestoneVote>, milestone_index: u8) -> Result<()> {
+        instructions::open_milestone_vote::handler(ctx, milestone_index)
+    }
+
+    pub fn vote_on_milestone(ctx: Context<VoteOnMilestone>, approve: bool) -> Result<()> {
+        instructions::vote_on_milestone::handler(ctx, approve)
+    }
+
+    pub fn pay_budget_item(ctx: Context<PayBudgetItem>, item_index: u8) -> Result<()> {
+        instructions::pay_budget_item::handler(ctx, item_index)
+    }
+
+    pub fn delegate_votes(
+        ctx: Context<DelegateVotes>,
+        event: Option<Pubkey>,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        instructions::delegate_votes::handler(ctx, event, delegate)
+    }
+
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        instructions::revoke_delegation::handler(ctx)
+    }
+
+    pub fn open_no_confidence(ctx: Context<OpenNoConfidence>) -> Result<()> {
+        instructions::open_no_confidence::handler(ctx)
+    }
+
+    pub fn vote_no_confidence(ctx: Context<VoteNoConfidence>, approve: bool) -> Result<()> {
+        instructions::vote_no_confidence::handler(ctx, approve)
+    }
+
     // ========== PROFIT DISTRIBUTION (3) ==========
     pub fn calculate_profits(ctx: Context<CalculateProfits>) -> Result<()> {
         instructions::calculate_profits::handler(ctx)
@@ -141,7 +281,7 @@ pub mod gatherfi {
         instructions::emergency_pause::handler(ctx)
     }
 
-    // ========== ADDITIONAL UTILITIES (3) ==========
+    // ========== ADDITIONAL UTILITIES (5) ==========
     pub fn update_event_category(
         ctx: Context<UpdateEventCategory>,
         category: EventCategory,
@@ -154,11 +294,108 @@ pub mod gatherfi {
         description: String,
         amount: u64,
         due_date: i64,
+        requires_vote: bool,
     ) -> Result<()> {
-        instructions::add_milestone::handler(ctx, description, amount, due_date)
+        instructions::add_milestone::handler(ctx, description, amount, due_date, requires_vote)
+    }
+
+    pub fn approve_milestone(ctx: Context<ApproveMilestone>, milestone_index: u8) -> Result<()> {
+        instructions::approve_milestone::handler(ctx, milestone_index)
+    }
+
+    pub fn configure_escrow_approvers(
+        ctx: Context<ConfigureEscrowApprovers>,
+        approvers: Vec<Pubkey>,
+        approvals_needed: u8,
+    ) -> Result<()> {
+        instructions::configure_escrow_approvers::handler(ctx, approvers, approvals_needed)
     }
 
     pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
         instructions::claim_refund::handler(ctx)
     }
+
+    // ========== PRICE ORACLE (2) ==========
+    pub fn initialize_price_feed(
+        ctx: Context<InitializePriceFeed>,
+        quote_mint: Pubkey,
+        kobo_per_unit: u64,
+        max_staleness: i64,
+    ) -> Result<()> {
+        instructions::initialize_price_feed::handler(
+            ctx,
+            quote_mint,
+            kobo_per_unit,
+            max_staleness,
+        )
+    }
+
+    pub fn update_price_feed(
+        ctx: Context<UpdatePriceFeed>,
+        kobo_per_unit: u64,
+        max_staleness: Option<i64>,
+    ) -> Result<()> {
+        instructions::update_price_feed::handler(ctx, kobo_per_unit, max_staleness)
+    }
+
+    // ========== PLATFORM CONFIG (2) ==========
+    pub fn initialize_platform_config(ctx: Context<InitializePlatformConfig>) -> Result<()> {
+        instructions::initialize_platform_config::handler(ctx)
+    }
+
+    pub fn update_platform_config(
+        ctx: Context<UpdatePlatformConfig>,
+        new_admin: Option<Pubkey>,
+        min_contribution: Option<u64>,
+        funding_period: Option<i64>,
+        platform_fee: Option<u64>,
+        backer_share: Option<u64>,
+        organizer_share: Option<u64>,
+        budget_voting_period: Option<i64>,
+        governance_cutoff: Option<i64>,
+    ) -> Result<()> {
+        instructions::update_platform_config::handler(
+            ctx,
+            new_admin,
+            min_contribution,
+            funding_period,
+            platform_fee,
+            backer_share,
+            organizer_share,
+            budget_voting_period,
+            governance_cutoff,
+        )
+    }
+
+    // ========== VENDORS (2) ==========
+    pub fn register_vendor(
+        ctx: Context<RegisterVendor>,
+        name: String,
+        payout_wallet: Pubkey,
+    ) -> Result<()> {
+        instructions::register_vendor::handler(ctx, name, payout_wallet)
+    }
+
+    pub fn verify_vendor(ctx: Context<VerifyVendor>, status: VendorStatus) -> Result<()> {
+        instructions::verify_vendor::handler(ctx, status)
+    }
+
+    // ========== RECEIPTS (3) ==========
+    pub fn attach_receipt(
+        ctx: Context<AttachReceipt>,
+        target: ReceiptTarget,
+        receipt_hash: [u8; 32],
+        uri: String,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::attach_receipt::handler(ctx, target, receipt_hash, uri, amount)
+    }
+
+    pub fn flag_receipt(ctx: Context<FlagReceipt>) -> Result<()> {
+        instructions::flag_receipt::handler(ctx)
+    }
+
+    pub fn resolve_receipt_dispute(ctx: Context<ResolveReceiptDispute>, upheld: bool) -> Result<()> {
+        instructions::resolve_receipt_dispute::handler(ctx, upheld)
+    }
 }
\ No newline at end of file
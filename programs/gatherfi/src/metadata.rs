@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{
+    self as token_metadata,
+    mpl_token_metadata::types::{Collection, CollectionDetails, DataV2},
+    CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata, VerifySizedCollectionItem,
+};
+use anchor_spl::token::Mint;
+use crate::state::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+// Ticket NFTs carry Token Metadata once the event has a ticket collection:
+//   [b"collection_mint", event] - the event's sized collection NFT, held by the event PDA
+// Each ticket mint gets a metadata account and a zero-supply master edition, and is
+// verified into the collection. The event PDA is mint, update and collection authority.
+
+pub struct MetadataRoute<'a, 'info> {
+    pub metadata: &'a UncheckedAccount<'info>,
+    pub master_edition: &'a UncheckedAccount<'info>,
+    pub collection_mint: &'a Account<'info, Mint>,
+    pub collection_metadata: &'a UncheckedAccount<'info>,
+    pub collection_master_edition: &'a UncheckedAccount<'info>,
+    pub token_metadata_program: &'a Program<'info, Metadata>,
+}
+
+// Resolves the optional metadata accounts of an instruction against the event's collection
+pub fn metadata_route<'a, 'info>(
+    event: &Event,
+    metadata: Option<&'a UncheckedAccount<'info>>,
+    master_edition: Option<&'a UncheckedAccount<'info>>,
+    collection_mint: Option<&'a Account<'info, Mint>>,
+    collection_metadata: Option<&'a UncheckedAccount<'info>>,
+    collection_master_edition: Option<&'a UncheckedAccount<'info>>,
+    token_metadata_program: Option<&'a Program<'info, Metadata>>,
+) -> Result<Option<MetadataRoute<'a, 'info>>> {
+    let collection = match event.collection_mint {
+        Some(collection) => collection,
+        None => return Ok(None),
+    };
+    
+    let (
+        Some(metadata),
+        Some(master_edition),
+        Some(collection_mint),
+        Some(collection_metadata),
+        Some(collection_master_edition),
+        Some(token_metadata_program),
+    ) = (
+        metadata,
+        master_edition,
+        collection_mint,
+        collection_metadata,
+        collection_master_edition,
+        token_metadata_program,
+    ) else {
+        return Err(error!(GatherFiError::MissingMetadataAccounts));
+    };
+    
+    require_keys_eq!(collection_mint.key(), collection, GatherFiError::InvalidCollection);
+    
+    Ok(Some(MetadataRoute {
+        metadata,
+        master_edition,
+        collection_mint,
+        collection_metadata,
+        collection_master_edition,
+        token_metadata_program,
+    }))
+}
+
+// Token Metadata caps names at 32 bytes, so the event name gives way to the suffix
+pub fn nft_name(base: &str, suffix: &str) -> String {
+    let mut end = base.len().min(MAX_NFT_NAME_LEN.saturating_sub(suffix.len()));
+    while !base.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}{}", &base[..end], suffix)
+}
+
+// Creates metadata and a master edition for a freshly minted NFT. The mint must
+// already hold its single token; the master edition takes over its mint authority.
+// token_program is SPL Token or Token-2022, whichever owns the mint
+#[allow(clippy::too_many_arguments)]
+pub fn create_nft<'info>(
+    event: &Account<'info, Event>,
+    payer: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    metadata: AccountInfo<'info>,
+    master_edition: AccountInfo<'info>,
+    token_metadata_program: &Program<'info, Metadata>,
+    token_program: AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    rent: &Sysvar<'info, Rent>,
+    data: DataV2,
+    collection_details: Option<CollectionDetails>,
+) -> Result<()> {
+    let event_seeds = event.signer_seeds();
+    let event_seeds = event_seeds.as_seeds();
+    let signer_seeds = &[&event_seeds[..]];
+    
+    let cpi_accounts = CreateMetadataAccountsV3 {
+        metadata: metadata.clone(),
+        mint: mint.clone(),
+        mint_authority: event.to_account_info(),
+        payer: payer.clone(),
+        update_authority: event.to_account_info(),
+        system_program: system_program.to_account_info(),
+        rent: rent.to_account_info(),
+    };
+    
+    let cpi_context = CpiContext::new_with_signer(
+        token_metadata_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    
+    token_metadata::create_metadata_accounts_v3(cpi_context, data, true, true, collection_details)?;
+    
+    let cpi_accounts = CreateMasterEditionV3 {
+        edition: master_edition,
+        mint,
+        update_authority: event.to_account_info(),
+        mint_authority: event.to_account_info(),
+        payer,
+        metadata,
+        token_program,
+        system_program: system_program.to_account_info(),
+        rent: rent.to_account_info(),
+    };
+    
+    let cpi_context = CpiContext::new_with_signer(
+        token_metadata_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    
+    // Zero supply: a ticket is one of one
+    token_metadata::create_master_edition_v3(cpi_context, Some(0))
+}
+
+// Mints the metadata of a ticket NFT and verifies it into the event's collection
+#[allow(clippy::too_many_arguments)]
+pub fn create_ticket_metadata<'info>(
+    event: &Account<'info, Event>,
+    payer: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    route: MetadataRoute<'_, 'info>,
+    token_program: AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    rent: &Sysvar<'info, Rent>,
+    name: String,
+    uri: String,
+) -> Result<()> {
+    let data = DataV2 {
+        name,
+        symbol: TICKET_NFT_SYMBOL.to_string(),
+        uri,
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: Some(Collection {
+            verified: false,
+            key: route.collection_mint.key(),
+        }),
+        uses: None,
+    };
+    
+    create_nft(
+        event,
+        payer.clone(),
+        mint,
+        route.metadata.to_account_info(),
+        route.master_edition.to_account_info(),
+        route.token_metadata_program,
+        token_program,
+        system_program,
+        rent,
+        data,
+        None,
+    )?;
+    
+    let event_seeds = event.signer_seeds();
+    let event_seeds = event_seeds.as_seeds();
+    let signer_seeds = &[&event_seeds[..]];
+    
+    let cpi_accounts = VerifySizedCollectionItem {
+        payer,
+        metadata: route.metadata.to_account_info(),
+        collection_authority: event.to_account_info(),
+        collection_mint: route.collection_mint.to_account_info(),
+        collection_metadata: route.collection_metadata.to_account_info(),
+        collection_master_edition: route.collection_master_edition.to_account_info(),
+    };
+    
+    let cpi_context = CpiContext::new_with_signer(
+        route.token_metadata_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    
+    token_metadata::verify_sized_collection_item(cpi_context, None)
+}
\ No newline at end of file
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::GatherFiError;
+
+// Events pay out in native SOL unless they chose a payment mint at creation.
+// SPL-denominated events keep funds in two token vaults owned by the escrow PDA:
+//   [b"escrow_vault", event]  - backer contributions, spent through milestones
+//   [b"revenue_vault", event] - ticket sales, paid out as profits
+// SOL events keep the same split in the lamports of the escrow and profit pool PDAs.
+
+pub struct TokenRoute<'a, 'info> {
+    pub user_account: &'a Account<'info, TokenAccount>,
+    pub vault: &'a Account<'info, TokenAccount>,
+    pub token_program: &'a Program<'info, Token>,
+}
+
+// Resolves the optional token accounts of an instruction against the event's payment mint
+pub fn token_route<'a, 'info>(
+    event: &Event,
+    user_account: Option<&'a Account<'info, TokenAccount>>,
+    vault: Option<&'a Account<'info, TokenAccount>>,
+    token_program: Option<&'a Program<'info, Token>>,
+) -> Result<Option<TokenRoute<'a, 'info>>> {
+    let mint = match event.payment_mint {
+        Some(mint) => mint,
+        None => return Ok(None),
+    };
+    
+    let (user_account, vault, token_program) = match (user_account, vault, token_program) {
+        (Some(user_account), Some(vault), Some(token_program)) => (user_account, vault, token_program),
+        _ => return Err(error!(GatherFiError::MissingTokenAccounts)),
+    };
+    
+    require_keys_eq!(user_account.mint, mint, GatherFiError::InvalidPaymentMint);
+    require_keys_eq!(vault.mint, mint, GatherFiError::InvalidPaymentMint);
+    
+    Ok(Some(TokenRoute {
+        user_account,
+        vault,
+        token_program,
+    }))
+}
+
+// Moves funds from a signer into a program vault
+pub fn collect<'info>(
+    payer: &Signer<'info>,
+    lamport_vault: AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    route: Option<TokenRoute<'_, 'info>>,
+    amount: u64,
+) -> Result<()> {
+    match route {
+        None => {
+            let transfer_instruction = anchor_lang::system_program::Transfer {
+                from: payer.to_account_info(),
+                to: lamport_vault,
+            };
+    
+            let cpi_context = CpiContext::new(
+                system_program.to_account_info(),
+                transfer_instruction,
+            );
+    
+            anchor_lang::system_program::transfer(cpi_context, amount)
+        }
+        Some(route) => {
+            let cpi_accounts = Transfer {
+                from: route.user_account.to_account_info(),
+                to: route.vault.to_account_info(),
+                authority: payer.to_account_info(),
+            };
+    
+            let cpi_context = CpiContext::new(route.token_program.to_account_info(), cpi_accounts);
+    
+            token::transfer(cpi_context, amount)
+        }
+    }
+}
+
+// Moves funds out of a program vault. Token vaults are signed for by the escrow PDA
+pub fn disburse<'info>(
+    escrow: &Account<'info, Escrow>,
+    lamport_vault: AccountInfo<'info>,
+    recipient: AccountInfo<'info>,
+    route: Option<TokenRoute<'_, 'info>>,
+    amount: u64,
+) -> Result<()> {
+    match route {
+        None => {
+            // Program-owned PDAs can't use the system program, so move lamports directly
+            let remaining = lamport_vault
+                .lamports()
+                .checked_sub(amount)
+                .ok_or(GatherFiError::InsufficientEscrowBalance)?;
+            let rent_exempt = Rent::get()?.minimum_balance(lamport_vault.data_len());
+            require!(remaining >= rent_exempt, GatherFiError::InsufficientEscrowBalance);
+    
+            **lamport_vault.try_borrow_mut_lamports()? = remaining;
+            **recipient.try_borrow_mut_lamports()? = recipient
+                .lamports()
+                .checked_add(amount)
+                .unwrap();
+    
+            Ok(())
+        }
+        Some(route) => {
+            let bump = escrow.bump;
+            let escrow_seeds: &[&[u8]] = &[
+                b"escrow",
+                escrow.event.as_ref(),
+                &[bump],
+            ];
+            let signer_seeds = &[escrow_seeds];
+    
+            let cpi_accounts = Transfer {
+                from: route.vault.to_account_info(),
+                to: route.user_account.to_account_info(),
+                authority: escrow.to_account_info(),
+            };
+    
+            let cpi_context = CpiContext::new_with_signer(
+                route.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+    
+            token::transfer(cpi_context, amount)
+        }
+    }
+}
\ No newline at end of file
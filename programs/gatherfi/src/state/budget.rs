@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::state::{BudgetCategory, BudgetItem, EventCategory};
+use crate::constants::*;
+use crate::errors::GatherFiError;
 
 #[account]
 #[derive(Default)]
@@ -9,16 +12,26 @@ pub struct Budget {
     pub total_amount: u64,
     pub amount_spent: u64,
     pub amount_remaining: u64,
+    pub category_caps: Vec<CategoryCap>,  // Set by the organizer, on top of the category policy
     
-    // Voting
+    // Versioning, earlier versions are archived as BudgetRevision accounts
+    pub version: u16,  // Latest submitted version, 0 = never submitted
+    pub approved_version: u16,  // Version in force, 0 = none approved yet
+    
+    // Voting (for the latest version)
     pub is_approved: bool,
     pub votes_for: u64,
     pub votes_against: u64,
     pub total_voters: u32,
     pub voting_ends_at: i64,
+    pub voting_period: i64,  // Snapshot of the platform budget voting window
+    pub snapshot_at: i64,  // Submission time, voting power is counted as of here
+    pub snapshot_voting_power: u64,  // Total vote weight eligible to vote
+    pub snapshot_backers: u32,  // Backers eligible to vote, for quorum
+    pub finalized_at: i64,
     
     // Status
-    pub is_locked: bool,
+    pub is_locked: bool,  // Vote on the latest version is finalized
     pub is_completed: bool,
     
     pub created_at: i64,
@@ -26,12 +39,105 @@ pub struct Budget {
     pub bump: u8,
 }
 
+impl Budget {
+    pub fn is_voting_open(&self, now: i64) -> bool {
+        self.version > 0 && !self.is_approved && !self.is_locked && now < self.voting_ends_at
+    }
+    
+    // Some(approved) once the result is settled, None while it can still change
+    pub fn vote_outcome(&self, now: i64) -> Option<bool> {
+        let quorum_reached = self.total_voters as u64 * BASIS_POINTS
+            >= self.snapshot_backers as u64 * BUDGET_VOTE_QUORUM;
+        let total = self.snapshot_voting_power as u128;
+        
+        // Decided early when the unvoted power can no longer flip the result
+        if quorum_reached && self.votes_for as u128 * 2 > total {
+            return Some(true);
+        }
+        if self.votes_against as u128 * 2 >= total {
+            return Some(false);
+        }
+        
+        // Otherwise the window decides on the votes cast
+        if now >= self.voting_ends_at {
+            return Some(quorum_reached && self.votes_for > self.votes_against);
+        }
+        None
+    }
+}
+
+// Limits one BudgetCategory to a share of the budget total
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct CategoryCap {
+    pub category: BudgetCategory,
+    pub max_share: u64,  // Basis points of total_amount
+}
+
+impl CategoryCap {
+    pub fn validate(caps: &[CategoryCap]) -> Result<()> {
+        require!(caps.len() <= MAX_CATEGORY_CAPS, GatherFiError::InvalidBudgetPolicy);
+        for (i, cap) in caps.iter().enumerate() {
+            require!(cap.max_share <= BASIS_POINTS, GatherFiError::InvalidBudgetPolicy);
+            require!(
+                !caps[..i].iter().any(|other| other.category == cap.category),
+                GatherFiError::InvalidBudgetPolicy
+            );
+        }
+        Ok(())
+    }
+    
+    pub fn check(caps: &[CategoryCap], items: &[BudgetItem], total_amount: u64) -> Result<()> {
+        for cap in caps {
+            let category_total: u128 = items
+                .iter()
+                .filter(|item| item.category == cap.category)
+                .map(|item| item.amount as u128)
+                .sum();
+            require!(
+                category_total * BASIS_POINTS as u128 <= total_amount as u128 * cap.max_share as u128,
+                GatherFiError::BudgetCapExceeded
+            );
+        }
+        Ok(())
+    }
+}
+
+// Admin-maintained caps applied to every budget of an EventCategory
+#[account]
+#[derive(Default)]
+pub struct BudgetPolicy {
+    pub category: EventCategory,
+    pub caps: Vec<CategoryCap>,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+// Snapshot of a superseded budget version and its vote result
+#[account]
+#[derive(Default)]
+pub struct BudgetRevision {
+    pub budget: Pubkey,
+    pub version: u16,
+    pub items: Vec<BudgetItem>,
+    pub total_amount: u64,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub total_voters: u32,
+    pub snapshot_voting_power: u64,
+    pub was_approved: bool,
+    pub submitted_at: i64,
+    pub superseded_at: i64,
+    pub bump: u8,
+}
+
 #[account]
 #[derive(Default)]
 pub struct Vote {
-    pub voter: Pubkey,
+    pub voter: Pubkey,  // Backer whose power was counted
+    pub cast_by: Pubkey,  // The backer, or their delegate
     pub budget: Pubkey,
     pub event: Pubkey,
+    pub version: u16,
     pub amount: u64,
     pub approve: bool,
     pub voted_at: i64,
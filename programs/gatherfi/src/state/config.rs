@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+// Singleton at [b"platform_config"]. New events snapshot these values at creation
+#[account]
+#[derive(Default)]
+pub struct PlatformConfig {
+    pub admin: Pubkey,
+    
+    // Funding
+    pub min_contribution: u64,
+    pub funding_period: i64,         // Seconds from creation to funding deadline
+    
+    // Profit split (basis points)
+    pub platform_fee: u64,
+    pub backer_share: u64,
+    pub organizer_share: u64,
+    
+    // Governance windows
+    pub budget_voting_period: i64,   // Seconds a submitted budget stays open for votes
+    pub governance_cutoff: i64,      // Seconds before the event date that voting closes
+    
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl PlatformConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.platform_fee <= MAX_PLATFORM_FEE, GatherFiError::PlatformFeeTooHigh);
+        let total_share = self.backer_share
+            .checked_add(self.organizer_share)
+            .and_then(|total| total.checked_add(self.platform_fee));
+        require!(
+            total_share == Some(BASIS_POINTS),
+            GatherFiError::InvalidProfitDistribution
+        );
+        require!(self.min_contribution > 0, GatherFiError::InsufficientContribution);
+        require!(
+            self.funding_period > 0 && self.budget_voting_period > 0 && self.governance_cutoff >= 0,
+            GatherFiError::InvalidConfig
+        );
+        Ok(())
+    }
+}
\ No newline at end of file
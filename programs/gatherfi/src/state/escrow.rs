@@ -7,6 +7,7 @@ pub struct Escrow {
     pub total_amount: u64,
     pub released_amount: u64,
     pub balance: u64,
+    pub milestone_cap: u64,  // Total of the approved budget version
     
     // Milestones
     pub milestone_count: u8,
@@ -15,6 +16,9 @@ pub struct Escrow {
     
     // Security
     pub is_locked: bool,
+    pub is_wound_down: bool,  // Backers passed a no-confidence vote
+    pub refund_pool: u64,  // Unreleased balance at wind-down, refunded pro-rata
+    pub open_disputes: u16,  // Flagged receipts, releases are frozen while non-zero
     pub requires_approval: bool,
     pub approvers: Vec<Pubkey>,
     pub approvals_needed: u8,
@@ -33,4 +37,16 @@ pub struct Milestone {
     pub released_at: Option<i64>,
     pub released_by: Option<Pubkey>,
     pub requires_vote: bool,
+    pub approval_count: u8,  // Distinct approvers who signed off
+    pub has_receipt: bool,
+}
+
+#[account]
+#[derive(Default)]
+pub struct MilestoneApproval {
+    pub escrow: Pubkey,
+    pub milestone_index: u8,
+    pub approver: Pubkey,
+    pub approved_at: i64,
+    pub bump: u8,
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
 pub enum EventCategory {
     Owambe,         // Traditional Nigerian party
     Concert,        // Music concert
@@ -11,14 +13,56 @@ pub enum EventCategory {
     Conference,     // Business conference
     Festival,       // Cultural festival
     Sports,         // Sporting event
+    #[default]
     Other,
 }
 
+impl EventCategory {
+    // Seed for per-category PDAs such as BudgetPolicy
+    pub fn seed(&self) -> [u8; 1] {
+        [self.clone() as u8]
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum EventStatus {
+    #[default]
+    Funding,        // Accepting contributions
+    Funded,         // Target met, tickets on sale
+    FailedFunding,  // Deadline passed below target, backers can refund
+    Cancelled,      // Cancelled by organizer before funding
+    Completed,      // Profits calculated, event settled
+    WoundDown,      // Backers passed a no-confidence vote, refunds only
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum VotingMode {
+    #[default]
+    Linear,             // 1 lamport = 1 vote
+    Quadratic,          // Square root of the contribution
+    Capped,             // Linear up to MAX_BACKER_VOTE_SHARE of the target
+    OneBackerOneVote,   // Every backer counts equally
+}
+
+impl EventStatus {
+    pub fn can_transition_to(&self, next: EventStatus) -> bool {
+        matches!(
+            (self, next),
+            (EventStatus::Funding, EventStatus::Funded)
+                | (EventStatus::Funding, EventStatus::FailedFunding)
+                | (EventStatus::Funding, EventStatus::Cancelled)
+                | (EventStatus::Funded, EventStatus::Completed)
+                | (EventStatus::Funded, EventStatus::WoundDown)
+        )
+    }
+}
+
 #[account]
 #[derive(Default)]
 pub struct Event {
     // Basic info
     pub organizer: Pubkey,
+    pub index: u64,  // Per-organizer sequence, part of the PDA seeds
     pub name: String,
     pub description: String,
     pub category: EventCategory,
@@ -27,10 +71,13 @@ pub struct Event {
     pub target_amount: u64,
     pub amount_raised: u64,
     pub min_contribution: u64,
+    pub payment_mint: Option<Pubkey>,  // None = native SOL
     
     // Ticketing
     pub ticket_price: u64,
+    pub priced_in_naira: bool,  // ticket_price is in kobo, converted at purchase
     pub tickets_sold: u32,
+    pub tickets_checked_in: u32,
     pub max_tickets: u32,
     pub revenue_from_tickets: u64,
     
@@ -41,19 +88,19 @@ pub struct Event {
     pub state: String,
     pub country: String,  // Always "Nigeria"
     
-    // Status flags
-    pub is_active: bool,
-    pub is_funded: bool,
-    pub is_cancelled: bool,
-    pub is_paused: bool,
-    pub is_finalized: bool,
+    // Status
+    pub status: EventStatus,
+    pub is_paused: bool,  // Emergency circuit breaker, independent of status
     
     // Governance
+    pub voting_mode: VotingMode,
     pub total_backers: u32,
+    pub total_voting_weight: u64,  // Sum of every backer's weight under voting_mode
     pub total_votes: u64,
     pub votes_for: u64,
     pub votes_against: u64,
     pub voting_ends_at: i64,
+    pub voting_snapshot_at: i64,  // Last budget submission, voting power is frozen here
     
     // Timestamps
     pub created_at: i64,
@@ -64,10 +111,75 @@ pub struct Event {
     pub escrow: Pubkey,
     pub profit_pool: Pubkey,
     pub budget: Pubkey,
+    pub venue_layout: Option<Pubkey>,  // Set once the organizer defines a seat map
+    pub collection_mint: Option<Pubkey>,  // Ticket NFTs are verified into this collection
+    pub transfer_policy: Option<Pubkey>,  // Resale rules, required for Token-2022 tickets
     
     pub bump: u8,
 }
 
+impl Event {
+    pub fn transition_to(&mut self, next: EventStatus) -> Result<()> {
+        require!(
+            self.status.can_transition_to(next),
+            GatherFiError::InvalidStatusTransition
+        );
+        self.status = next;
+        Ok(())
+    }
+    
+    // Seeds the event PDA signs with, as mint and freeze authority of its tickets
+    pub fn signer_seeds(&self) -> EventSignerSeeds {
+        EventSignerSeeds {
+            organizer: self.organizer,
+            index: self.index.to_le_bytes(),
+            bump: [self.bump],
+        }
+    }
+    
+    // Converts a backer's raw voting power into votes under the event's voting mode
+    pub fn vote_weight(&self, voting_power: u64) -> u64 {
+        match self.voting_mode {
+            VotingMode::Linear => voting_power,
+            VotingMode::Quadratic => integer_sqrt(voting_power),
+            VotingMode::Capped => {
+                let cap = (self.target_amount as u128 * MAX_BACKER_VOTE_SHARE as u128
+                    / BASIS_POINTS as u128) as u64;
+                voting_power.min(cap.max(1))
+            }
+            VotingMode::OneBackerOneVote => u64::from(voting_power > 0),
+        }
+    }
+}
+
+// Owns the byte seeds so callers can borrow them for the length of a CPI
+pub struct EventSignerSeeds {
+    organizer: Pubkey,
+    index: [u8; 8],
+    bump: [u8; 1],
+}
+
+impl EventSignerSeeds {
+    pub fn as_seeds(&self) -> [&[u8]; 4] {
+        [b"event", self.organizer.as_ref(), &self.index, &self.bump]
+    }
+}
+
+fn integer_sqrt(value: u64) -> u64 {
+    if value < 2 {
+        return value;
+    }
+    
+    // Newton's method, converges from above
+    let mut x = value;
+    let mut y = x / 2 + x % 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
 #[account]
 #[derive(Default)]
 pub struct Contribution {
@@ -75,21 +187,45 @@ pub struct Contribution {
     pub event: Pubkey,
     pub amount: u64,
     pub voting_power: u64,  // 1 lamport = 1 vote
+    pub snapshot_power: u64,  // voting_power as of snapshot_at
+    pub snapshot_at: i64,
     pub claimed_profits: u64,
     pub claimed_refund: bool,
     pub created_at: i64,
     pub bump: u8,
 }
 
+impl Contribution {
+    // Called before voting_power changes, so a top-up after a budget is submitted
+    // doesn't count towards that budget's vote
+    pub fn checkpoint(&mut self, snapshot_at: i64) {
+        if snapshot_at > self.snapshot_at {
+            self.snapshot_power = self.voting_power;
+            self.snapshot_at = snapshot_at;
+        }
+    }
+    
+    pub fn voting_power_at(&self, snapshot_at: i64) -> u64 {
+        if self.snapshot_at == snapshot_at {
+            self.snapshot_power
+        } else {
+            // No change since the snapshot was taken
+            self.voting_power
+        }
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct BudgetItem {
     pub name: String,
     pub description: String,
     pub amount: u64,
-    pub vendor: String,
+    pub vendor: Pubkey,  // Vendor PDA, the only payee for this item
     pub category: BudgetCategory,
     pub is_paid: bool,
     pub paid_at: Option<i64>,
+    pub paid_version: u16,  // Budget version the item was paid under
+    pub has_receipt: bool,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
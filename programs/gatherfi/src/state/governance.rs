@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+// Backer vote on a single milestone flagged requires_vote
+#[account]
+#[derive(Default)]
+pub struct MilestoneProposal {
+    pub event: Pubkey,
+    pub escrow: Pubkey,
+    pub milestone_index: u8,
+    pub round: u8,               // Bumped each time a failed vote is reopened
+    
+    // Voting
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub total_voters: u32,
+    pub quorum: u64,             // Voting power that must be cast
+    pub voting_ends_at: i64,
+    
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl MilestoneProposal {
+    pub fn has_passed(&self, now: i64) -> bool {
+        let turnout = self.votes_for as u128 + self.votes_against as u128;
+        
+        now >= self.voting_ends_at
+            && turnout >= self.quorum as u128
+            && self.votes_for as u128 * BASIS_POINTS as u128
+                > turnout * MILESTONE_VOTE_THRESHOLD as u128
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct MilestoneVote {
+    pub voter: Pubkey,
+    pub proposal: Pubkey,
+    pub amount: u64,
+    pub approve: bool,
+    pub voted_at: i64,
+    pub bump: u8,
+}
+
+// Backer vote to remove the organizer. Passing locks escrow and opens pro-rata refunds
+#[account]
+#[derive(Default)]
+pub struct NoConfidenceProposal {
+    pub event: Pubkey,
+    pub proposer: Pubkey,
+    pub round: u8,  // Bumped each time a failed vote is reopened
+    
+    // Voting
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub total_voters: u32,
+    pub threshold: u64,          // Votes for needed to pass
+    pub voting_ends_at: i64,
+    pub passed: bool,
+    
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(Default)]
+pub struct NoConfidenceVote {
+    pub voter: Pubkey,
+    pub proposal: Pubkey,
+    pub amount: u64,
+    pub approve: bool,
+    pub voted_at: i64,
+    pub bump: u8,
+}
+
+// Lets a backer's voting power be cast by another wallet. Scoped to one event,
+// or to every event the backer funds when event is None. A backer's per-event
+// delegation takes precedence over their all-events one
+#[account]
+#[derive(Default)]
+pub struct Delegation {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub event: Option<Pubkey>,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Delegation {
+    pub fn covers(&self, delegator: Pubkey, delegate: Pubkey, event: Pubkey) -> bool {
+        self.delegator == delegator
+            && self.delegate == delegate
+            && (self.event.is_none() || self.event == Some(event))
+    }
+}
\ No newline at end of file
@@ -2,10 +2,24 @@ pub mod event;
 pub mod ticket;
 pub mod escrow;
 pub mod budget;
-pub mod profits;
+pub mod profit;
+pub mod organizer;
+pub mod price_feed;
+pub mod config;
+pub mod governance;
+pub mod vendor;
+pub mod receipt;
+pub mod venue;
 
 pub use event::*;
 pub use ticket::*;
 pub use escrow::*;
 pub use budget::*;
-pub use profits::*;
\ No newline at end of file
+pub use profit::*;
+pub use organizer::*;
+pub use price_feed::*;
+pub use config::*;
+pub use governance::*;
+pub use vendor::*;
+pub use receipt::*;
+pub use venue::*;
\ No newline at end of file
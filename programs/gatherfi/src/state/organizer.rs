@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+#[account]
+#[derive(Default)]
+pub struct OrganizerProfile {
+    pub organizer: Pubkey,
+    
+    // Track record
+    pub events_created: u64,     // Also the next event index
+    pub events_completed: u32,
+    pub events_cancelled: u32,
+    
+    // Funding
+    pub total_raised: u64,       // Raised by completed events
+    pub total_refunded: u64,     // Paid back to backers by refund_contribution
+    
+    // Attendance, both counted when an event completes
+    pub tickets_sold: u64,
+    pub tickets_checked_in: u64,
+    
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl OrganizerProfile {
+    pub fn record_completion(&mut self, tickets_sold: u32, tickets_checked_in: u32) -> Result<()> {
+        self.events_completed = self.events_completed
+            .checked_add(1)
+            .ok_or(GatherFiError::Overflow)?;
+        self.tickets_sold = self.tickets_sold
+            .checked_add(tickets_sold as u64)
+            .ok_or(GatherFiError::Overflow)?;
+        self.tickets_checked_in = self.tickets_checked_in
+            .checked_add(tickets_checked_in as u64)
+            .ok_or(GatherFiError::Overflow)?;
+        
+        Ok(())
+    }
+    
+    // Basis points of all tickets sold for completed events that were checked in
+    pub fn attendance_rate(&self) -> u16 {
+        if self.tickets_sold == 0 {
+            return 0;
+        }
+        
+        (self.tickets_checked_in as u128 * BASIS_POINTS as u128 / self.tickets_sold as u128)
+            .min(BASIS_POINTS as u128) as u16
+    }
+}
\ No newline at end of file
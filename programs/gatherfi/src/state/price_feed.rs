@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::errors::GatherFiError;
+
+// Admin-maintained NGN rate for one payment currency, addressed as
+// [b"price_feed", quote_mint] with Pubkey::default() standing in for native SOL
+#[account]
+#[derive(Default)]
+pub struct PriceFeed {
+    pub authority: Pubkey,
+    pub quote_mint: Pubkey,
+    pub decimals: u8,           // 9 for SOL, 6 for USDC/USDT
+    pub kobo_per_unit: u64,     // Kobo per whole SOL or token
+    pub max_staleness: i64,     // Seconds before the rate is rejected
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl PriceFeed {
+    // Converts a kobo amount into base units (lamports or token units) at the current rate
+    pub fn kobo_to_units(&self, kobo: u64, now: i64) -> Result<u64> {
+        require!(
+            now.saturating_sub(self.updated_at) <= self.max_staleness,
+            GatherFiError::StalePriceFeed
+        );
+        
+        let scale = 10u128
+            .checked_pow(self.decimals as u32)
+            .ok_or(GatherFiError::InvalidPriceFeed)?;
+        let units = (kobo as u128)
+            .checked_mul(scale)
+            .and_then(|units| units.checked_div(self.kobo_per_unit as u128))
+            .ok_or(GatherFiError::InvalidPriceFeed)?;
+        
+        u64::try_from(units).map_err(|_| error!(GatherFiError::InvalidTicketPrice))
+    }
+}
\ No newline at end of file
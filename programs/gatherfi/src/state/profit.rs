@@ -20,6 +20,9 @@ pub struct ProfitPool {
     pub backer_share: u64,       // 60%
     pub organizer_share: u64,    // 35%
     pub platform_share: u64,     // 5%
+    pub backer_amount: u64,      // Shares of net profit, set by calculate_profits
+    pub organizer_amount: u64,
+    pub platform_amount: u64,
     
     // Status
     pub is_calculated: bool,
@@ -31,6 +34,7 @@ pub struct ProfitPool {
     pub total_backers: u32,
     
     pub created_at: i64,
+    pub updated_at: i64,
     pub bump: u8,
 }
 
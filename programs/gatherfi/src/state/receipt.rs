@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReceiptTarget {
+    BudgetItem { version: u16, index: u8 },
+    Milestone { index: u8 },
+}
+
+impl Default for ReceiptTarget {
+    fn default() -> Self {
+        ReceiptTarget::Milestone { index: 0 }
+    }
+}
+
+// Proof of spend for a paid budget item or released milestone. Backers can flag it
+// during the challenge window, which freezes escrow releases until it's resolved.
+// An upheld dispute locks escrow for good
+#[account]
+#[derive(Default)]
+pub struct Receipt {
+    pub event: Pubkey,
+    pub target: ReceiptTarget,
+    pub submitted_by: Pubkey,  // Organizer or the paid vendor
+    pub receipt_hash: [u8; 32],
+    pub uri: String,
+    pub amount: u64,
+    pub submitted_at: i64,
+    pub challenge_ends_at: i64,
+    
+    // Dispute
+    pub is_flagged: bool,
+    pub flagged_by: Option<Pubkey>,
+    pub is_resolved: bool,
+    pub dispute_upheld: bool,  // Admin sided with the backer
+    
+    pub bump: u8,
+}
\ No newline at end of file
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
+use crate::errors::GatherFiError;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
 pub enum TicketType {
+    #[default]
     Regular,
     VIP,
     EarlyBird,
@@ -12,6 +14,12 @@ pub enum TicketType {
     Table,
 }
 
+impl TicketType {
+    pub fn seed(&self) -> [u8; 1] {
+        [*self as u8]
+    }
+}
+
 #[account]
 #[derive(Default)]
 pub struct Ticket {
@@ -23,10 +31,16 @@ pub struct Ticket {
     pub zone: String,
     pub seat: Option<String>,
     
+    // Table tickets
+    pub parent: Option<Pubkey>,  // Set on guest tickets issued by a table
+    pub guest_slots: u8,
+    pub guests_issued: u8,
+    
     // Status
     pub is_checked_in: bool,
     pub is_refunded: bool,
     pub is_transferred: bool,
+    pub transfer_count: u8,
     
     // Financial
     pub purchase_price: u64,
@@ -42,9 +56,88 @@ pub struct Ticket {
     pub bump: u8,
 }
 
+impl Ticket {
+    // A table is a parent ticket; it admits guests through its guest tickets
+    pub fn is_table(&self) -> bool {
+        self.ticket_type == TicketType::Table && self.parent.is_none()
+    }
+}
+
 #[account]
 #[derive(Default)]
 pub struct TicketCounter {
     pub count: u32,
     pub bump: u8,
+}
+
+// Inventory for one ticket type of an event
+#[account]
+#[derive(Default)]
+pub struct TicketTier {
+    pub event: Pubkey,
+    pub ticket_type: TicketType,
+    pub price: u64,  // Same unit as event.ticket_price
+    pub supply: u32,
+    pub sold: u32,
+    pub guests_per_ticket: u8,  // Table tier only
+    pub is_active: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+// A paid group booking. Ticket numbers first_ticket.. are reserved for the
+// recipients in order and minted one at a time
+#[account]
+#[derive(Default)]
+pub struct GroupOrder {
+    pub event: Pubkey,
+    pub buyer: Pubkey,
+    pub zone: String,
+    pub first_ticket: u32,
+    pub recipients: Vec<Pubkey>,
+    pub issued: u8,
+    pub price_per_ticket: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl GroupOrder {
+    pub fn is_complete(&self) -> bool {
+        self.issued as usize >= self.recipients.len()
+    }
+    
+    pub fn next_ticket_number(&self) -> u32 {
+        self.first_ticket + self.issued as u32
+    }
+}
+
+// Resale rules for an event's tickets, enforced by transfer_ticket and by the
+// transfer hook on Token-2022 ticket mints
+#[account]
+#[derive(Default)]
+pub struct TransferPolicy {
+    pub event: Pubkey,
+    pub transfers_enabled: bool,
+    pub max_transfers: u8,  // Per ticket, 0 = unlimited
+    pub cutoff: i64,  // Transfers close this long before the event date
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl TransferPolicy {
+    pub fn check(&self, ticket: &Ticket, event_date: i64, now: i64) -> Result<()> {
+        require!(self.transfers_enabled, GatherFiError::TransfersDisabled);
+        require!(!ticket.is_checked_in, GatherFiError::AlreadyCheckedIn);
+        require!(!ticket.is_refunded, GatherFiError::AlreadyRefunded);
+        require!(
+            self.max_transfers == 0 || ticket.transfer_count < self.max_transfers,
+            GatherFiError::TransferLimitReached
+        );
+        require!(
+            now < event_date.saturating_sub(self.cutoff),
+            GatherFiError::TransfersClosed
+        );
+        Ok(())
+    }
 }
\ No newline at end of file
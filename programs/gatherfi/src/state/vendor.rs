@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum VendorStatus {
+    #[default]
+    Unverified,     // Listed, can be named in budgets but not paid
+    Verified,       // Checked by the platform admin, can receive payouts
+    Suspended,      // Payouts blocked
+}
+
+#[account]
+#[derive(Default)]
+pub struct Vendor {
+    pub authority: Pubkey,  // Registered the listing
+    pub payout_wallet: Pubkey,
+    pub name: String,
+    pub status: VendorStatus,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
\ No newline at end of file
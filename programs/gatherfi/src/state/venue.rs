@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::errors::GatherFiError;
+
+// Seats are addressed by row (0 = "A") and a 1-based number within the row
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct SeatPosition {
+    pub row: u8,
+    pub number: u16,
+}
+
+impl SeatPosition {
+    pub fn seed(&self) -> [u8; 3] {
+        let number = self.number.to_le_bytes();
+        [self.row, number[0], number[1]]
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct VenueZone {
+    pub name: String,
+    pub capacity: u32,
+    pub rows: u8,  // 0 = general admission, no seats
+    pub seats_per_row: u16,
+    pub sold: u32,
+}
+
+impl VenueZone {
+    pub fn is_seated(&self) -> bool {
+        self.rows > 0
+    }
+    
+    // Label printed on the ticket, e.g. "VIP-B12"
+    pub fn seat_label(&self, position: SeatPosition) -> Result<String> {
+        require!(
+            self.is_seated()
+                && position.row < self.rows
+                && position.number >= 1
+                && position.number <= self.seats_per_row,
+            GatherFiError::InvalidSeat
+        );
+        Ok(format!("{}-{}{}", self.name, (b'A' + position.row) as char, position.number))
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct VenueLayout {
+    pub event: Pubkey,
+    pub zones: Vec<VenueZone>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl VenueLayout {
+    pub fn validate(zones: &[VenueZone], max_tickets: u32) -> Result<()> {
+        require!(
+            !zones.is_empty() && zones.len() <= MAX_VENUE_ZONES,
+            GatherFiError::InvalidVenueLayout
+        );
+    
+        let mut total_capacity: u64 = 0;
+        for (i, zone) in zones.iter().enumerate() {
+            require!(
+                !zone.name.is_empty() && zone.name.len() <= MAX_ZONE_NAME_LEN,
+                GatherFiError::InvalidVenueLayout
+            );
+            require!(
+                zones[..i].iter().all(|other| other.name != zone.name),
+                GatherFiError::InvalidVenueLayout
+            );
+            require!(zone.capacity > 0, GatherFiError::InvalidVenueLayout);
+    
+            // Seated zones hold exactly one ticket per seat
+            if zone.is_seated() {
+                require!(
+                    zone.rows <= MAX_VENUE_ROWS
+                        && zone.capacity as u64 == zone.rows as u64 * zone.seats_per_row as u64,
+                    GatherFiError::InvalidVenueLayout
+                );
+            } else {
+                require!(zone.seats_per_row == 0, GatherFiError::InvalidVenueLayout);
+            }
+    
+            total_capacity += zone.capacity as u64;
+        }
+        require!(
+            total_capacity <= max_tickets as u64,
+            GatherFiError::InvalidVenueLayout
+        );
+    
+        Ok(())
+    }
+    
+    pub fn zone_mut(&mut self, name: &str) -> Result<&mut VenueZone> {
+        self.zones
+            .iter_mut()
+            .find(|zone| zone.name == name)
+            .ok_or(error!(GatherFiError::InvalidZone))
+    }
+}
+
+// One account per sold seat, so a seat can never be issued twice
+#[account]
+#[derive(Default)]
+pub struct Seat {
+    pub event: Pubkey,
+    pub zone: String,
+    pub position: SeatPosition,
+    pub ticket: Pubkey,
+    pub assigned_at: i64,
+    pub bump: u8,
+}
\ No newline at end of file
@@ -0,0 +1,528 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_interface::{self, MintTo};
+use anchor_spl::metadata::Metadata;
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+use crate::state::*;
+use crate::errors::GatherFiError;
+use crate::payments;
+use crate::metadata::{self, MetadataRoute};
+
+// Single ticket sales are shared by mint_ticket (SPL Token) and mint_hooked_ticket
+// (Token-2022 with the transfer hook), and table guests by issue_table_guest and
+// issue_hooked_table_guest. Each instruction nests the shared accounts below and adds
+// only its own mint, token account and token program.
+
+// The NFT half of a ticket, minted by whichever token program owns the mint
+pub struct TicketNft<'info> {
+    pub mint: AccountInfo<'info>,
+    pub holder: AccountInfo<'info>,  // Token account that receives the NFT
+    pub token_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(ticket_type: TicketType, zone: String, seat_position: Option<SeatPosition>)]
+pub struct TicketSaleAccounts<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = event.status == EventStatus::Funded @ GatherFiError::EventNotFunded,
+        constraint = !event.is_paused @ GatherFiError::EventPaused,
+        constraint = event.tickets_sold < event.max_tickets @ GatherFiError::TicketsSoldOut,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        seeds = [b"ticket_counter", event.key().as_ref()],
+        bump = ticket_counter.bump
+    )]
+    pub ticket_counter: Account<'info, TicketCounter>,
+    
+    #[account(
+        mut,
+        seeds = [b"ticket_tier", event.key().as_ref(), &ticket_type.seed()],
+        bump = ticket_tier.bump,
+        constraint = ticket_tier.is_active @ GatherFiError::TicketTypeUnavailable,
+        constraint = ticket_tier.sold < ticket_tier.supply @ GatherFiError::TicketsSoldOut,
+    )]
+    pub ticket_tier: Account<'info, TicketTier>,
+    
+    // Required once the organizer has set a venue layout
+    #[account(
+        mut,
+        seeds = [b"venue_layout", event.key().as_ref()],
+        bump = venue_layout.bump
+    )]
+    pub venue_layout: Option<Account<'info, VenueLayout>>,
+    
+    // Only when a seat is picked at purchase. Fails to initialize if the seat was already taken
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + Seat::SIZE,
+        seeds = [
+            b"seat",
+            event.key().as_ref(),
+            zone.as_bytes(),
+            &seat_position.unwrap_or_default().seed(),
+        ],
+        bump
+    )]
+    pub seat: Option<Account<'info, Seat>>,
+    
+    // Ticket and mint PDAs are keyed on the next ticket number (u32, little-endian)
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + Ticket::SIZE,
+        seeds = [b"ticket", event.key().as_ref(), &ticket_counter.count.to_le_bytes()],
+        bump
+    )]
+    pub ticket: Account<'info, Ticket>,
+    
+    #[account(
+        mut,
+        seeds = [b"profits", event.key().as_ref()],
+        bump = profit_pool.bump
+    )]
+    pub profit_pool: Account<'info, ProfitPool>,
+    
+    // Required when the event is priced in Naira
+    #[account(
+        seeds = [b"price_feed", event.payment_mint.unwrap_or_default().as_ref()],
+        bump = price_feed.bump
+    )]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+    
+    // Token accounts, required when the event has a payment mint
+    #[account(mut)]
+    pub buyer_payment_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"revenue_vault", event.key().as_ref()],
+        bump
+    )]
+    pub revenue_vault: Option<Account<'info, TokenAccount>>,
+    
+    // Token Metadata accounts, required once the event has a ticket collection
+    /// CHECK: Created and validated by the Token Metadata program
+    #[account(mut)]
+    pub ticket_metadata: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Created and validated by the Token Metadata program
+    #[account(mut)]
+    pub ticket_master_edition: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        seeds = [b"collection_mint", event.key().as_ref()],
+        bump
+    )]
+    pub collection_mint: Option<Account<'info, Mint>>,
+    
+    /// CHECK: Validated by the Token Metadata program against collection_mint
+    #[account(mut)]
+    pub collection_metadata: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Validated by the Token Metadata program against collection_mint
+    pub collection_master_edition: Option<UncheckedAccount<'info>>,
+    
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
+    
+    // SPL Token, for payments in the event's payment mint
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct TableGuestAccounts<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        constraint = event.status == EventStatus::Funded @ GatherFiError::EventNotFunded,
+        constraint = !event.is_paused @ GatherFiError::EventPaused,
+    )]
+    pub event: Account<'info, Event>,
+    
+    #[account(
+        mut,
+        has_one = event,
+        has_one = owner @ GatherFiError::NotTicketOwner,
+        constraint = table_ticket.is_table() @ GatherFiError::NotTableTicket,
+        constraint = !table_ticket.is_refunded @ GatherFiError::AlreadyRefunded,
+        constraint = table_ticket.guests_issued < table_ticket.guest_slots @ GatherFiError::TableFull,
+    )]
+    pub table_ticket: Account<'info, Ticket>,
+    
+    /// CHECK: Any wallet can hold a guest ticket
+    pub guest: UncheckedAccount<'info>,
+    
+    // Guest tickets and mints are keyed on the table and the guest index
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Ticket::SIZE,
+        seeds = [b"guest_ticket", table_ticket.key().as_ref(), &[table_ticket.guests_issued]],
+        bump
+    )]
+    pub guest_ticket: Account<'info, Ticket>,
+    
+    // Token Metadata accounts, required once the event has a ticket collection
+    /// CHECK: Created and validated by the Token Metadata program
+    #[account(mut)]
+    pub guest_metadata: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Created and validated by the Token Metadata program
+    #[account(mut)]
+    pub guest_master_edition: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        seeds = [b"collection_mint", event.key().as_ref()],
+        bump
+    )]
+    pub collection_mint: Option<Account<'info, Mint>>,
+    
+    /// CHECK: Validated by the Token Metadata program against collection_mint
+    #[account(mut)]
+    pub collection_metadata: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Validated by the Token Metadata program against collection_mint
+    pub collection_master_edition: Option<UncheckedAccount<'info>>,
+    
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
+    
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// Records the sale, collects its price and mints the NFT to the buyer
+pub fn sell_ticket<'info>(
+    sale: &mut TicketSaleAccounts<'info>,
+    nft: TicketNft<'info>,
+    ticket_type: TicketType,
+    zone: String,
+    seat_position: Option<SeatPosition>,
+    ticket_bump: u8,
+    seat_bump: Option<u8>,
+) -> Result<()> {
+    let TicketSaleAccounts {
+        buyer,
+        event,
+        ticket_counter,
+        ticket_tier,
+        venue_layout,
+        seat,
+        ticket,
+        profit_pool,
+        price_feed,
+        buyer_payment_account,
+        revenue_vault,
+        ticket_metadata,
+        ticket_master_edition,
+        collection_mint,
+        collection_metadata,
+        collection_master_edition,
+        token_metadata_program,
+        token_program,
+        system_program,
+        rent,
+    } = sale;
+    let clock = Clock::get()?;
+    
+    // Validate ticket purchase
+    require!(clock.unix_timestamp < event.event_date, GatherFiError::EventDatePassed);
+    
+    // Group tickets are sold per group through mint_group_tickets
+    require!(ticket_type != TicketType::Group, GatherFiError::TicketTypeUnavailable);
+    
+    // Claim the next ticket number
+    let ticket_number = ticket_counter.count;
+    ticket_counter.count = ticket_counter.count.checked_add(1).unwrap();
+    
+    // Price comes from the tier the organizer set for this ticket type
+    let base_price = ticket_tier.price;
+    let guest_slots = ticket_tier.guests_per_ticket;
+    ticket_tier.sold = ticket_tier.sold.checked_add(1).unwrap();
+    
+    // Events with a seat map sell against zone capacity. A table holds its guests'
+    // places as well as its own
+    let seat_requested = seat.is_some();
+    let seat_label = match event.venue_layout {
+        Some(_) => {
+            let venue_layout = venue_layout.as_mut().ok_or(GatherFiError::VenueLayoutRequired)?;
+            let venue_zone = venue_layout.zone_mut(&zone)?;
+            let zone_sold = venue_zone.sold.checked_add(1 + guest_slots as u32).unwrap();
+            require!(zone_sold <= venue_zone.capacity, GatherFiError::ZoneSoldOut);
+            venue_zone.sold = zone_sold;
+    
+            match seat_position {
+                Some(position) => {
+                    let label = venue_zone.seat_label(position)?;
+                    let seat = seat.as_mut().ok_or(GatherFiError::InvalidSeat)?;
+                    seat.event = event.key();
+                    seat.zone = zone.clone();
+                    seat.position = position;
+                    seat.ticket = ticket.key();
+                    seat.assigned_at = clock.unix_timestamp;
+                    seat.bump = seat_bump.unwrap();
+                    Some(label)
+                }
+                None => None,
+            }
+        }
+        None => None,
+    };
+    require!(seat_label.is_some() == seat_requested, GatherFiError::InvalidSeat);
+    
+    // Convert Naira prices at the current rate
+    let ticket_price = if event.priced_in_naira {
+        let price_feed = price_feed.as_ref().ok_or(GatherFiError::PriceFeedRequired)?;
+        price_feed.kobo_to_units(base_price, clock.unix_timestamp)?
+    } else {
+        base_price
+    };
+    require!(ticket_price > 0, GatherFiError::InvalidTicketPrice);
+    
+    // Transfer payment from buyer to the profit pool (SOL) or revenue vault (tokens)
+    let payment = payments::token_route(
+        event,
+        buyer_payment_account.as_ref(),
+        revenue_vault.as_ref(),
+        Some(&*token_program),
+    )?;
+    
+    payments::collect(
+        buyer,
+        profit_pool.to_account_info(),
+        system_program,
+        payment,
+        ticket_price,
+    )?;
+    
+    // Initialize ticket
+    ticket.mint = nft.mint.key();
+    ticket.event = event.key();
+    ticket.owner = buyer.key();
+    ticket.ticket_number = ticket_number;
+    ticket.ticket_type = ticket_type;
+    ticket.zone = zone;
+    ticket.seat = seat_label; // None until assign_seat
+    ticket.parent = None;
+    ticket.guest_slots = guest_slots;
+    ticket.guests_issued = 0;
+    ticket.is_checked_in = false;
+    ticket.is_refunded = false;
+    ticket.is_transferred = false;
+    ticket.transfer_count = 0;
+    ticket.purchase_price = ticket_price;
+    ticket.purchase_time = clock.unix_timestamp;
+    ticket.checked_in_time = None;
+    ticket.check_in_staff = None;
+    
+    // Generate metadata URI for Nigerian context
+    ticket.metadata_uri = format!(
+        "https://ipfs.gatherfi.ng/tickets/{}/{}.json",
+        event.key(),
+        ticket_number
+    );
+    ticket.bump = ticket_bump;
+    
+    // Update event
+    event.tickets_sold = event.tickets_sold.checked_add(1).unwrap();
+    event.revenue_from_tickets = event.revenue_from_tickets.checked_add(ticket_price).unwrap();
+    event.updated_at = clock.unix_timestamp;
+    
+    // Update profit pool
+    profit_pool.total_revenue = profit_pool.total_revenue.checked_add(ticket_price).unwrap();
+    profit_pool.updated_at = clock.unix_timestamp;
+    
+    // Mint the NFT to the buyer
+    mint_nft(
+        event,
+        buyer.to_account_info(),
+        nft,
+        metadata::metadata_route(
+            event,
+            ticket_metadata.as_ref(),
+            ticket_master_edition.as_ref(),
+            collection_mint.as_ref(),
+            collection_metadata.as_ref(),
+            collection_master_edition.as_ref(),
+            token_metadata_program.as_ref(),
+        )?,
+        system_program,
+        rent,
+        metadata::nft_name(&event.name, &format!(" #{}", ticket_number)),
+        ticket.metadata_uri.clone(),
+    )?;
+    
+    msg!("🎟️  Ticket #{} minted for {}", ticket_number, event.name);
+    msg!("👤 Owner: {}", buyer.key());
+    msg!("💰 Price: {}", ticket_price);
+    msg!("🎫 Type: {:?}", ticket.ticket_type);
+    msg!("📍 Zone: {}", ticket.zone);
+    
+    Ok(())
+}
+
+// Issues a table's next guest ticket and mints its NFT to the guest. Guests share
+// the table's number, zone and seat; the table carries the price
+pub fn issue_guest<'info>(
+    guests: &mut TableGuestAccounts<'info>,
+    nft: TicketNft<'info>,
+    guest_bump: u8,
+) -> Result<()> {
+    let TableGuestAccounts {
+        owner,
+        event,
+        table_ticket,
+        guest,
+        guest_ticket,
+        guest_metadata,
+        guest_master_edition,
+        collection_mint,
+        collection_metadata,
+        collection_master_edition,
+        token_metadata_program,
+        system_program,
+        rent,
+    } = guests;
+    let clock = Clock::get()?;
+    
+    let guest_index = table_ticket.guests_issued;
+    table_ticket.guests_issued = table_ticket.guests_issued.checked_add(1).unwrap();
+    
+    guest_ticket.mint = nft.mint.key();
+    guest_ticket.event = event.key();
+    guest_ticket.owner = guest.key();
+    guest_ticket.ticket_number = table_ticket.ticket_number;
+    guest_ticket.ticket_type = TicketType::Table;
+    guest_ticket.zone = table_ticket.zone.clone();
+    guest_ticket.seat = table_ticket.seat.clone();
+    guest_ticket.parent = Some(table_ticket.key());
+    guest_ticket.guest_slots = 0;
+    guest_ticket.guests_issued = 0;
+    guest_ticket.is_checked_in = false;
+    guest_ticket.is_refunded = false;
+    guest_ticket.is_transferred = false;
+    guest_ticket.transfer_count = 0;
+    guest_ticket.purchase_price = 0;
+    guest_ticket.purchase_time = clock.unix_timestamp;
+    guest_ticket.checked_in_time = None;
+    guest_ticket.check_in_staff = None;
+    guest_ticket.metadata_uri = format!(
+        "https://ipfs.gatherfi.ng/tickets/{}/{}-{}.json",
+        event.key(),
+        table_ticket.ticket_number,
+        guest_index
+    );
+    guest_ticket.bump = guest_bump;
+    
+    // Mint the NFT to the guest
+    mint_nft(
+        event,
+        owner.to_account_info(),
+        nft,
+        metadata::metadata_route(
+            event,
+            guest_metadata.as_ref(),
+            guest_master_edition.as_ref(),
+            collection_mint.as_ref(),
+            collection_metadata.as_ref(),
+            collection_master_edition.as_ref(),
+            token_metadata_program.as_ref(),
+        )?,
+        system_program,
+        rent,
+        metadata::nft_name(
+            &event.name,
+            &format!(" #{}-{}", table_ticket.ticket_number, guest_index),
+        ),
+        guest_ticket.metadata_uri.clone(),
+    )?;
+    
+    msg!(
+        "🍽️  Guest {} of {} issued for table #{}",
+        table_ticket.guests_issued,
+        table_ticket.guest_slots,
+        table_ticket.ticket_number
+    );
+    msg!("👤 Guest: {}", guest.key());
+    
+    Ok(())
+}
+
+// Mints a ticket NFT, signed by the event PDA as mint authority, then gives it
+// metadata once the event has a ticket collection
+#[allow(clippy::too_many_arguments)]
+fn mint_nft<'info>(
+    event: &Account<'info, Event>,
+    payer: AccountInfo<'info>,
+    nft: TicketNft<'info>,
+    metadata_route: Option<MetadataRoute<'_, 'info>>,
+    system_program: &Program<'info, System>,
+    rent: &Sysvar<'info, Rent>,
+    name: String,
+    uri: String,
+) -> Result<()> {
+    let event_seeds = event.signer_seeds();
+    let event_seeds = event_seeds.as_seeds();
+    let signer_seeds = &[&event_seeds[..]];
+    
+    let cpi_accounts = MintTo {
+        mint: nft.mint.clone(),
+        to: nft.holder,
+        authority: event.to_account_info(),
+    };
+    
+    let cpi_context = CpiContext::new_with_signer(
+        nft.token_program.clone(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    
+    token_interface::mint_to(cpi_context, 1)?;
+    
+    if let Some(route) = metadata_route {
+        metadata::create_ticket_metadata(
+            event,
+            payer,
+            nft.mint,
+            route,
+            nft.token_program,
+            system_program,
+            rent,
+            name,
+            uri,
+        )?;
+    }
+    
+    Ok(())
+}
+
+// Writes the accounts the transfer hook resolves on every transfer of a Token-2022
+// ticket: the ticket, its event and the event's transfer policy
+pub fn init_transfer_hook_accounts(
+    extra_account_meta_list: &AccountInfo,
+    ticket: Pubkey,
+    event: &Account<'_, Event>,
+) -> Result<()> {
+    let transfer_policy = event.transfer_policy.ok_or(GatherFiError::TransferPolicyRequired)?;
+    let extra_metas = [
+        ExtraAccountMeta::new_with_pubkey(&ticket, false, true)?,
+        ExtraAccountMeta::new_with_pubkey(&event.key(), false, false)?,
+        ExtraAccountMeta::new_with_pubkey(&transfer_policy, false, false)?,
+    ];
+    ExtraAccountMetaList::init::<ExecuteInstruction>(
+        &mut extra_account_meta_list.try_borrow_mut_data()?,
+        &extra_metas,
+    )?;
+    
+    Ok(())
+}
\ No newline at end of file